@@ -0,0 +1,96 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+use crate::mat::Matrix;
+
+// SAFETY: `Matrix` is `repr(transparent)` over `[T; LEN]`, which is zeroable if `T` is.
+// This holds for any `M`, `N` and `LEN`, but a matrix made through bytemuck skips `Matrix::new`,
+// so nothing checks `M * N == LEN` for it; the methods below assert it at compile time instead.
+unsafe impl<T: Zeroable, const M: usize, const N: usize, const LEN: usize> Zeroable
+    for Matrix<T, M, N, LEN>
+{
+}
+
+// SAFETY: `Matrix` is `repr(transparent)` over `[T; LEN]`, which has no padding and is valid for
+// any bit pattern if `T` is, and it's `Copy` when `T` is. As with `Zeroable`, the shape invariant
+// `M * N == LEN` is only asserted by the methods below, not by bytemuck's own casts.
+unsafe impl<T: Pod, const M: usize, const N: usize, const LEN: usize> Pod for Matrix<T, M, N, LEN> {}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    const ASSERT_SHAPE: () = assert!(M * N == LEN, "LEN must be M * N");
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Pod,
+{
+    /// Views the elements as bytes, in row-major order and native endianness (requires the `bytemuck` feature).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![1u16, 2; 3, 4];
+    /// assert_eq!(mat.as_bytes().len(), 8);
+    /// assert_eq!(&mat.as_bytes()[..2], &1u16.to_ne_bytes());
+    /// ```
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_SHAPE;
+        bytemuck::bytes_of(self)
+    }
+
+    /// Same as `as_bytes`, but mutable.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_SHAPE;
+        bytemuck::bytes_of_mut(self)
+    }
+
+    /// Views some bytes as a matrix without copying (requires the `bytemuck` feature).
+    ///
+    /// # Errors
+    /// * `PodCastError::SizeMismatch` if there aren't exactly `LEN` elements' worth of bytes.
+    /// * `PodCastError::TargetAlignmentGreaterAndInputNotAligned` if the bytes aren't aligned for `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![1u8, 2, 3; 4, 5, 6];
+    /// let view = Matrix::<u8, 3, 2, 6>::from_bytes(mat.as_bytes()).unwrap();
+    /// assert_eq!(view, &matrix![1, 2; 3, 4; 5, 6]);
+    /// ```
+    ///
+    /// A `LEN` that isn't `M * N` is a compile-time error, since there's no `Matrix::new` to check it:
+    /// ```compile_fail
+    /// use qmat::prelude::*;
+    /// let view = Matrix::<u8, 2, 2, 6>::from_bytes(&[0; 6]);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, PodCastError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_SHAPE;
+        bytemuck::try_from_bytes(bytes)
+    }
+
+    /// Views some bytes as a slice of matrices without copying (requires the `bytemuck` feature),
+    /// e.g. for a buffer or memory-mapped file holding many matrices back-to-back.
+    ///
+    /// # Errors
+    /// * `PodCastError::OutputSliceWouldHaveSlop` if the bytes aren't a whole number of matrices.
+    /// * `PodCastError::TargetAlignmentGreaterAndInputNotAligned` if the bytes aren't aligned for `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    /// let mats = Matrix::<u8, 2, 2, 4>::cast_slice(&bytes).unwrap();
+    /// assert_eq!(mats, &[matrix![1, 2; 3, 4], matrix![5, 6; 7, 8]]);
+    /// ```
+    pub fn cast_slice(bytes: &[u8]) -> Result<&[Self], PodCastError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_SHAPE;
+        bytemuck::try_cast_slice(bytes)
+    }
+}
@@ -0,0 +1,96 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use num_complex::Complex;
+use num_traits::{One, Zero};
+
+use crate::{identities::RealField, mat::Matrix};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<Complex<T>, M, N, LEN>
+where
+    T: RealField,
+{
+    /// Returns a matrix with the [complex conjugate](https://en.wikipedia.org/wiki/Complex_conjugate) of every element.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use qmat::prelude::*;
+    /// let mat = matrix![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0); Complex::new(0.0, 1.0), Complex::new(5.0, 0.0)];
+    /// assert_eq!(
+    ///     mat.conjugate(),
+    ///     matrix![Complex::new(1.0, -2.0), Complex::new(3.0, 4.0); Complex::new(0.0, -1.0), Complex::new(5.0, 0.0)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        let mut out = *self;
+        for elem in &mut out {
+            *elem = elem.conj();
+        }
+        out
+    }
+
+    /// Returns the [conjugate transpose](https://en.wikipedia.org/wiki/Conjugate_transpose) (a.k.a. Hermitian adjoint).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use qmat::prelude::*;
+    /// let mat = row_vector![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+    /// assert_eq!(mat.adjoint(), col_vector![Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]);
+    /// ```
+    #[must_use]
+    pub fn adjoint(&self) -> Matrix<Complex<T>, N, M, LEN> {
+        self.transpose().conjugate()
+    }
+}
+
+impl<T, const M: usize, const LEN: usize> Matrix<Complex<T>, M, M, LEN>
+where
+    T: RealField,
+{
+    /// Returns whether the matrix is [Hermitian](https://en.wikipedia.org/wiki/Hermitian_matrix) (equal to its own adjoint),
+    /// allowing each element to be off by at most `tolerance`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use qmat::prelude::*;
+    /// let mat = matrix![Complex::new(2.0, 0.0), Complex::new(1.0, -1.0); Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)];
+    /// assert!(mat.is_hermitian(0.0));
+    /// assert!(!mat.mul_scalar(Complex::new(0.0, 1.0)).is_hermitian(0.0));
+    /// ```
+    #[must_use]
+    pub fn is_hermitian(&self, tolerance: T) -> bool {
+        (0..M).all(|row| {
+            (row..M).all(|col| (self[[row, col]] - self[[col, row]].conj()).norm() <= tolerance)
+        })
+    }
+
+    /// Returns whether the matrix is [unitary](https://en.wikipedia.org/wiki/Unitary_matrix) (its adjoint is its inverse),
+    /// allowing each element of `self.adjoint() * self` to be off from the identity by at most `tolerance`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use qmat::prelude::*;
+    /// let h = std::f64::consts::FRAC_1_SQRT_2;
+    /// let hadamard = matrix![Complex::new(h, 0.0), Complex::new(h, 0.0); Complex::new(h, 0.0), Complex::new(-h, 0.0)];
+    /// assert!(hadamard.is_unitary(1e-12));
+    /// assert!(!hadamard.mul_scalar(Complex::new(2.0, 0.0)).is_unitary(1e-12));
+    /// ```
+    #[must_use]
+    pub fn is_unitary(&self, tolerance: T) -> bool {
+        let product = self.adjoint().multiply::<M, LEN, LEN>(self);
+        (0..M).all(|row| {
+            (0..M).all(|col| {
+                let expected = if row == col {
+                    Complex::one()
+                } else {
+                    Complex::zero()
+                };
+                (product[[row, col]] - expected).norm() <= tolerance
+            })
+        })
+    }
+}
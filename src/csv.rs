@@ -0,0 +1,185 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Reading and writing matrices as CSV (requires the `csv` feature).
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{csv::CsvOptions, prelude::*};
+//!
+//! let mat = matrix![1, 2, 3; 4, 5, 6];
+//! let mut buf = Vec::new();
+//! mat.to_csv(&mut buf, &CsvOptions::default()).unwrap();
+//! assert_eq!(String::from_utf8(buf.clone()).unwrap(), "1,2,3\n4,5,6\n");
+//!
+//! let read = Matrix::<i32, 2, 3, 6>::from_csv(buf.as_slice(), &CsvOptions::default()).unwrap();
+//! assert_eq!(read, mat);
+//! ```
+
+use std::{
+    fmt::Display,
+    io::{Read, Write},
+    str::FromStr,
+};
+
+use crate::{dmat::DMatrix, errors::CsvError, mat::Matrix, position::Position};
+
+/// Options for reading and writing CSV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    /// The byte separating elements in a row. Defaults to `b','`.
+    pub delimiter: u8,
+
+    /// Whether there's a header row. It's skipped when reading, and `column_names` is written in
+    /// its place when writing. Defaults to `false`.
+    pub has_header: bool,
+
+    /// The names written in the header row. Columns without a name are numbered from 0.
+    pub column_names: Vec<String>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: false,
+            column_names: Vec::new(),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    /// Writes the matrix as CSV, one row per line.
+    ///
+    /// # Errors
+    /// * `CsvError::Csv` if writing fails.
+    pub fn to_csv<W: Write>(&self, writer: W, options: &CsvOptions) -> Result<(), CsvError>
+    where
+        T: Display,
+    {
+        write_csv(writer, self.as_flat_array(), N, options)
+    }
+
+    /// Reads a matrix from CSV, one row per line.
+    /// Surrounding whitespace is trimmed from each element before it's parsed.
+    ///
+    /// # Errors
+    /// * `CsvError::Csv` if reading fails or the CSV is malformed.
+    /// * `CsvError::InvalidElement` if an element can't be parsed.
+    /// * `CsvError::RaggedRow` if the rows aren't all the same length.
+    /// * `CsvError::DimensionMismatch` if there aren't `M` rows of `N` elements.
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Self, CsvError>
+    where
+        T: FromStr,
+    {
+        let (rows, cols, data) = read_csv(reader, options)?;
+        let mismatch = CsvError::DimensionMismatch {
+            expected: (M, N),
+            found: (rows, cols),
+        };
+        if rows != M || cols != N {
+            return Err(mismatch);
+        }
+        match data.try_into() {
+            Ok(data) => Matrix::new(data).map_err(|_| mismatch),
+            Err(_) => Err(mismatch),
+        }
+    }
+}
+
+impl<T> DMatrix<T> {
+    /// Same as `Matrix::to_csv`.
+    ///
+    /// # Errors
+    /// * `CsvError::Csv` if writing fails.
+    pub fn to_csv<W: Write>(&self, writer: W, options: &CsvOptions) -> Result<(), CsvError>
+    where
+        T: Display,
+    {
+        write_csv(writer, self.as_flat_slice(), self.cols(), options)
+    }
+
+    /// Same as `Matrix::from_csv`, but the dimensions are taken from the CSV.
+    ///
+    /// # Errors
+    /// * `CsvError::Csv` if reading fails or the CSV is malformed.
+    /// * `CsvError::InvalidElement` if an element can't be parsed.
+    /// * `CsvError::RaggedRow` if the rows aren't all the same length.
+    ///
+    /// # Panics
+    /// * Never, as ragged rows are already rejected while reading.
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Self, CsvError>
+    where
+        T: FromStr,
+    {
+        let (rows, cols, data) = read_csv(reader, options)?;
+        Ok(DMatrix::new(rows, cols, data).expect("every row has `cols` elements"))
+    }
+}
+
+fn write_csv<T, W>(writer: W, data: &[T], cols: usize, options: &CsvOptions) -> Result<(), CsvError>
+where
+    T: Display,
+    W: Write,
+{
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .flexible(true)
+        .from_writer(writer);
+
+    if options.has_header {
+        writer.write_record((0..cols).map(|col| match options.column_names.get(col) {
+            Some(name) => name.clone(),
+            None => col.to_string(),
+        }))?;
+    }
+    if cols > 0 {
+        for row in data.chunks(cols) {
+            writer.write_record(row.iter().map(ToString::to_string))?;
+        }
+    }
+
+    writer.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
+/// Returns the rows, cols and row-major elements.
+fn read_csv<T, R>(reader: R, options: &CsvOptions) -> Result<(usize, usize, Vec<T>), CsvError>
+where
+    T: FromStr,
+    R: Read,
+{
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .has_headers(options.has_header)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let mut rows = 0;
+    let mut cols = 0;
+    let mut data = Vec::new();
+
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        if row == 0 {
+            cols = record.len();
+        } else if record.len() != cols {
+            return Err(CsvError::RaggedRow {
+                row,
+                expected: cols,
+                found: record.len(),
+            });
+        }
+
+        for (col, field) in record.iter().enumerate() {
+            let elem = field.parse().map_err(|_| CsvError::InvalidElement {
+                pos: Position(row, col),
+                text: field.to_string(),
+            })?;
+            data.push(elem);
+        }
+        rows += 1;
+    }
+
+    Ok((rows, cols, data))
+}
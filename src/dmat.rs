@@ -0,0 +1,189 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::ops::{Index, IndexMut};
+
+use crate::{errors::NewMatrixError, mat::Matrix, position::Position};
+
+/// A heap-allocated matrix whose dimensions are only known at runtime.
+///
+/// Stores its elements in the same row-major order as [`Matrix`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> DMatrix<T> {
+    /// Creates a new `DMatrix` from given dimensions and flat data.
+    ///
+    /// # Errors
+    /// * `NewMatrixError::IllegalArguments` if `rows * cols != data.len()`, including if `rows * cols` overflows
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = DMatrix::new(2, 3, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    /// assert_eq!(mat[[1, 0]], 3);
+    /// assert!(DMatrix::new(2, 2, vec![0, 1, 2]).is_err());
+    /// ```
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, NewMatrixError> {
+        let expected = rows.checked_mul(cols);
+        if expected != Some(data.len()) {
+            return Err(NewMatrixError::IllegalArguments {
+                expected: expected.unwrap_or(usize::MAX),
+                found: data.len(),
+            });
+        }
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Creates a new `DMatrix` from nested rows.
+    ///
+    /// # Errors
+    /// * `NewMatrixError::RaggedRows` if the rows aren't all the same length
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = DMatrix::from_rows(vec![vec![0, 1], vec![2, 3], vec![4, 5]]).unwrap();
+    /// assert_eq!(mat.rows(), 3);
+    /// assert_eq!(mat.cols(), 2);
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, NewMatrixError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let n_rows = rows.len();
+        let mut data = Vec::with_capacity(n_rows * cols);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(NewMatrixError::RaggedRows {
+                    row: i,
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+        }
+        Self::new(n_rows, cols, data)
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The number of elements in the matrix (i.e. the number of rows times the number of cols).
+    #[must_use]
+    pub fn vol(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns an immutable reference to the underlying 1-dimensional data.
+    /// Flattened in the same way as `Matrix::as_flat_array`.
+    #[must_use]
+    pub fn as_flat_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the underlying 1-dimensional data.
+    #[must_use]
+    pub fn as_flat_slice_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Consumes the matrix, returning the underlying 1-dimensional data.
+    #[must_use]
+    pub fn into_flat_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Iterates over immutable references to all of the elements of a matrix.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DMatrix<T> {
+    type IntoIter = std::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Index<[usize; 2]> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: [usize; 2]) -> &Self::Output {
+        assert!(pos[1] < self.cols, "column index out of bounds");
+        &self.data[pos[0] * self.cols + pos[1]]
+    }
+}
+
+impl<T> IndexMut<[usize; 2]> for DMatrix<T> {
+    fn index_mut(&mut self, pos: [usize; 2]) -> &mut Self::Output {
+        assert!(pos[1] < self.cols, "column index out of bounds");
+        &mut self.data[pos[0] * self.cols + pos[1]]
+    }
+}
+
+impl<T> Index<Position> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        &self[[pos.0, pos.1]]
+    }
+}
+
+impl<T> IndexMut<Position> for DMatrix<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        &mut self[[pos.0, pos.1]]
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> From<Matrix<T, M, N, LEN>>
+    for DMatrix<T>
+{
+    fn from(mat: Matrix<T, M, N, LEN>) -> Self {
+        Self {
+            rows: M,
+            cols: N,
+            data: mat.into_flat_array().into(),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> TryFrom<DMatrix<T>>
+    for Matrix<T, M, N, LEN>
+{
+    type Error = NewMatrixError;
+
+    /// # Errors
+    /// * `NewMatrixError::GenericArgumentMismatch` if the dimensions don't match `M` and `N`
+    /// * Same as `Matrix::new`
+    fn try_from(mat: DMatrix<T>) -> Result<Self, Self::Error> {
+        if mat.rows != M || mat.cols != N {
+            return Err(NewMatrixError::GenericArgumentMismatch {
+                expected: (M, N),
+                found: (mat.rows, mat.cols),
+            });
+        }
+        let data: [T; LEN] = mat
+            .data
+            .try_into()
+            .map_err(|_| NewMatrixError::IllegalGenerics {
+                m: M,
+                n: N,
+                len: LEN,
+            })?;
+        Self::new(data)
+    }
+}
@@ -1,19 +1,535 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-#[derive(Debug, PartialEq)]
+use std::{fmt, io};
+
+#[cfg(feature = "matfile")]
+use crate::matfile::MatClass;
+use crate::{mtx::MtxField, position::Position};
+
+/// An error when creating a matrix.
+#[derive(Debug, PartialEq, Clone)]
 pub enum NewMatrixError {
-    /// If the given arguments aren't internally consistent (i.e. if `rows*cols!=data.len()`)
-    IllegalArguments,
+    /// If the given arguments aren't internally consistent (i.e. if `rows*cols!=data.len()`).
+    /// Holds `rows*cols` (or `usize::MAX` if that overflows) and `data.len()`.
+    IllegalArguments { expected: usize, found: usize },
+
+    /// If a row doesn't have the same number of elements as the first row.
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
 
     /// If the generics aren't internally consistent (i.e. if `M*N!=LEN`)
-    IllegalGenerics,
+    IllegalGenerics { m: usize, n: usize, len: usize },
 
-    /// If the generics don't match the given arguments
-    GenericArgumentMismatch,
+    /// If the generics don't match the given arguments.
+    /// Holds `(M, N)` and the given `(rows, cols)`.
+    GenericArgumentMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
 }
 
-#[derive(Debug, PartialEq)]
+impl fmt::Display for NewMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IllegalArguments { expected, found } => {
+                write!(f, "expected {expected} elements, found {found}")
+            }
+            Self::RaggedRows {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} has {found} elements, expected {expected}"),
+            Self::IllegalGenerics { m, n, len } => {
+                write!(f, "LEN must be M * N, but {m} * {n} != {len}")
+            }
+            Self::GenericArgumentMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NewMatrixError {}
+
+/// An error when performing an operation on a matrix.
+#[derive(Debug, PartialEq, Clone)]
 pub enum MatrixOperationError {
-    /// If the determinant was invalid for the attempted operation.
+    /// If the determinant was invalid for the attempted operation (i.e. zero when inverting).
+    #[deprecated(note = "singular matrices are reported as `Singular`")]
     InvalidDeterminant,
+
+    /// If the matrix is singular, so there's no inverse or unique solution.
+    Singular,
+
+    /// If the operation needs a square matrix. Holds the matrix's `(rows, cols)`.
+    NotSquare { rows: usize, cols: usize },
+
+    /// If the operands' dimensions, as `(rows, cols)`, aren't compatible.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+
+    /// If an iterative algorithm didn't converge within its iteration limit.
+    NotConverged { iterations: usize },
+
+    /// If the operation needs a positive-definite matrix.
+    NotPositiveDefinite,
+
+    /// If a position is outside of the matrix. Holds the position and the matrix's `(rows, cols)`.
+    OutOfBounds { pos: Position, dims: (usize, usize) },
+
+    /// If the output matrix couldn't be created (i.e. if its `LEN` generic isn't right).
+    IllegalOutput(NewMatrixError),
+}
+
+impl fmt::Display for MatrixOperationError {
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDeterminant => {
+                f.write_str("the determinant is invalid for this operation")
+            }
+            Self::Singular => f.write_str("the matrix is singular"),
+            Self::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix, found {rows}x{cols}")
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::NotConverged { iterations } => {
+                write!(f, "didn't converge after {iterations} iterations")
+            }
+            Self::NotPositiveDefinite => f.write_str("the matrix isn't positive-definite"),
+            Self::OutOfBounds { pos, dims } => write!(
+                f,
+                "position {pos} is out of bounds for a {}x{} matrix",
+                dims.0, dims.1
+            ),
+            Self::IllegalOutput(e) => write!(f, "couldn't create the output: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixOperationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IllegalOutput(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NewMatrixError> for MatrixOperationError {
+    fn from(e: NewMatrixError) -> Self {
+        Self::IllegalOutput(e)
+    }
+}
+
+/// An error when parsing a matrix from a string.
+/// `line` and `col` are 1-indexed and point to where in the string the error occurred.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseMatrixError {
+    pub line: usize,
+    pub col: usize,
+    pub kind: ParseMatrixErrorKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseMatrixErrorKind {
+    /// If an element couldn't be parsed into the element type. Holds the offending text.
+    InvalidElement(String),
+
+    /// If a row doesn't have the same number of elements as the first row.
+    RaggedRow { expected: usize, found: usize },
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+
+    /// If a bracket is closed without being opened, or is never closed.
+    UnbalancedBracket,
+}
+
+impl fmt::Display for ParseMatrixErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidElement(text) => write!(f, "invalid element `{text}`"),
+            Self::RaggedRow { expected, found } => {
+                write!(f, "row has {found} elements, expected {expected}")
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::UnbalancedBracket => f.write_str("unbalanced bracket"),
+        }
+    }
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.col, self.kind)
+    }
+}
+
+impl std::error::Error for ParseMatrixError {}
+
+/// An error when reading or writing a matrix as CSV.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    /// If the underlying reader or writer failed, or the CSV was malformed.
+    Csv(csv::Error),
+
+    /// If an element couldn't be parsed into the element type.
+    /// Holds the position of the element (not counting the header) and the offending text.
+    InvalidElement { pos: Position, text: String },
+
+    /// If a row doesn't have the same number of elements as the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+#[cfg(feature = "csv")]
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Csv(e) => write!(f, "csv error: {e}"),
+            Self::InvalidElement { pos, text } => write!(f, "invalid element `{text}` at {pos}"),
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} has {found} elements, expected {expected}"),
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for CsvError {
+    fn from(e: csv::Error) -> Self {
+        Self::Csv(e)
+    }
+}
+
+/// An error when reading a Matrix Market file.
+/// Lines are 1-indexed; positions are 0-indexed like everywhere else in the crate.
+#[derive(Debug)]
+pub enum MtxError {
+    /// If the underlying reader or writer failed.
+    Io(io::Error),
+
+    /// If the banner or size line is missing or malformed.
+    InvalidHeader { line: usize, text: String },
+
+    /// If the file's field can't be stored in the element type (e.g. `complex` in an `f64` matrix).
+    IncompatibleField(MtxField),
+
+    /// If an entry couldn't be parsed. Holds the offending line.
+    InvalidEntry { line: usize, text: String },
+
+    /// If an entry's position is outside of the dimensions from the size line.
+    EntryOutOfBounds { line: usize, pos: Position },
+
+    /// If there are fewer or more entries than the size line says.
+    WrongEntryCount { expected: usize, found: usize },
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl fmt::Display for MtxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::InvalidHeader { line, text } => write!(f, "line {line}: invalid header `{text}`"),
+            Self::IncompatibleField(field) => {
+                write!(f, "a `{field}` field can't be stored in this element type")
+            }
+            Self::InvalidEntry { line, text } => write!(f, "line {line}: invalid entry `{text}`"),
+            Self::EntryOutOfBounds { line, pos } => {
+                write!(f, "line {line}: entry {pos} is out of bounds")
+            }
+            Self::WrongEntryCount { expected, found } => {
+                write!(f, "expected {expected} entries, found {found}")
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MtxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MtxError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// An error when reading a numpy `.npy` file (requires the `npy` feature).
+#[cfg(feature = "npy")]
+#[derive(Debug)]
+pub enum NpyError {
+    /// If the underlying reader failed, or the data ended early.
+    Io(io::Error),
+
+    /// If the file doesn't start with the `.npy` magic string.
+    InvalidMagic,
+
+    /// If the format version, as `(major, minor)`, isn't 1.0, 2.0 or 3.0.
+    UnsupportedVersion(u8, u8),
+
+    /// If the header couldn't be parsed. Holds the header.
+    InvalidHeader(String),
+
+    /// If the array's dtype isn't the element type. Both are given as dtype strings, e.g. `<f8`.
+    DtypeMismatch { expected: String, found: String },
+
+    /// If the array isn't 1 or 2-dimensional. Holds the array's shape.
+    UnsupportedShape(Vec<usize>),
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+#[cfg(feature = "npy")]
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::InvalidMagic => f.write_str("not a .npy file"),
+            Self::UnsupportedVersion(major, minor) => {
+                write!(f, "unsupported .npy version {major}.{minor}")
+            }
+            Self::InvalidHeader(header) => write!(f, "invalid header `{header}`"),
+            Self::DtypeMismatch { expected, found } => {
+                write!(f, "expected dtype `{expected}`, found `{found}`")
+            }
+            Self::UnsupportedShape(shape) => {
+                write!(
+                    f,
+                    "expected a 1 or 2-dimensional array, found shape {shape:?}"
+                )
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "npy")]
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "npy")]
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// An error when reading a `.mat` file or getting a matrix from it (requires the `matfile` feature).
+#[cfg(feature = "matfile")]
+#[derive(Debug)]
+pub enum MatFileError {
+    /// If the underlying reader failed, or decompressing a variable failed.
+    Io(io::Error),
+
+    /// If the file doesn't have a Level 5 MAT-file header.
+    InvalidHeader,
+
+    /// If a data element is malformed. Holds a description of the problem.
+    InvalidElement(String),
+
+    /// If there's no variable with the given name.
+    NotFound(String),
+
+    /// If the named variable isn't a real, 2-dimensional numeric array.
+    UnsupportedArray(String),
+
+    /// If the named variable's class isn't the element type's.
+    ClassMismatch {
+        name: String,
+        expected: MatClass,
+        found: MatClass,
+    },
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+#[cfg(feature = "matfile")]
+impl fmt::Display for MatFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::InvalidHeader => f.write_str("not a Level 5 MAT-file"),
+            Self::InvalidElement(problem) => write!(f, "invalid data element: {problem}"),
+            Self::NotFound(name) => write!(f, "no variable named `{name}`"),
+            Self::UnsupportedArray(name) => {
+                write!(f, "`{name}` isn't a real, 2-dimensional numeric array")
+            }
+            Self::ClassMismatch {
+                name,
+                expected,
+                found,
+            } => write!(f, "expected `{name}` to be {expected}, found {found}"),
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "matfile")]
+impl std::error::Error for MatFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "matfile")]
+impl From<io::Error> for MatFileError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// An error when reading a Netpbm image.
+#[derive(Debug)]
+pub enum NetpbmError {
+    /// If the underlying reader failed.
+    Io(io::Error),
+
+    /// If the magic number isn't one of the expected (ASCII, binary) pair. Holds what was found.
+    WrongFormat {
+        expected: (&'static str, &'static str),
+        found: String,
+    },
+
+    /// If the width, height or maxval is missing or malformed.
+    InvalidHeader(String),
+
+    /// If the maxval is larger than the sample type can hold.
+    MaxvalTooLarge(u16),
+
+    /// If a sample is malformed or larger than the maxval. Holds the sample's index in the raster.
+    InvalidSample { index: usize },
+
+    /// If there are fewer samples than the width and height say.
+    Truncated,
+
+    /// If the number of rows and columns, as `(rows, cols)`, doesn't match the matrix type.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl fmt::Display for NetpbmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::WrongFormat { expected, found } => write!(
+                f,
+                "expected magic number `{}` or `{}`, found `{found}`",
+                expected.0, expected.1
+            ),
+            Self::InvalidHeader(problem) => write!(f, "invalid header: {problem}"),
+            Self::MaxvalTooLarge(maxval) => {
+                write!(f, "maxval {maxval} is too large for the sample type")
+            }
+            Self::InvalidSample { index } => write!(f, "sample {index} is invalid"),
+            Self::Truncated => f.write_str("image is truncated"),
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetpbmError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NetpbmError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
 }
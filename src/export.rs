@@ -0,0 +1,170 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Rendering matrices as LaTeX, Markdown or Rust source.
+//!
+//! Each renderer takes an optional precision, which is applied to every element like `{:.N}` would.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{export::LatexEnvironment, prelude::*};
+//!
+//! let mat = matrix![1.0, 0.5; -2.0, 4.25];
+//! assert_eq!(
+//!     mat.to_latex(LatexEnvironment::Bmatrix, Some(2)),
+//!     "\\begin{bmatrix}\n1.00 & 0.50 \\\\\n-2.00 & 4.25\n\\end{bmatrix}"
+//! );
+//! assert_eq!(
+//!     mat.to_markdown(None),
+//!     "| 0 | 1 |\n| --: | --: |\n| 1 | 0.5 |\n| -2 | 4.25 |\n"
+//! );
+//! assert_eq!(mat.to_rust_literal(None), "qmat::matrix!([[1.0, 0.5], [-2.0, 4.25]])");
+//! ```
+
+use std::fmt::{Debug, Display, Write};
+
+use crate::{dmat::DMatrix, mat::Matrix};
+
+/// The LaTeX matrix environment to render in, from the `amsmath` package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexEnvironment {
+    /// Square brackets.
+    Bmatrix,
+    /// Parentheses.
+    Pmatrix,
+}
+
+impl LatexEnvironment {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Bmatrix => "bmatrix",
+            Self::Pmatrix => "pmatrix",
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    /// Renders the matrix as a LaTeX `bmatrix` or `pmatrix`, with rows separated by `\\`.
+    #[must_use]
+    pub fn to_latex(&self, env: LatexEnvironment, precision: Option<usize>) -> String
+    where
+        T: Display,
+    {
+        latex(self.as_flat_array(), N, env, precision)
+    }
+
+    /// Renders the matrix as a Markdown table, with the column indices as the header.
+    #[must_use]
+    pub fn to_markdown(&self, precision: Option<usize>) -> String
+    where
+        T: Display,
+    {
+        markdown(self.as_flat_array(), N, precision)
+    }
+
+    /// Renders the matrix as a `qmat::matrix!` invocation that recreates it.
+    ///
+    /// Elements are written with `Debug` so that floats keep their decimal point,
+    /// unless a precision is given (a precision of 0 will drop it).
+    #[must_use]
+    pub fn to_rust_literal(&self, precision: Option<usize>) -> String
+    where
+        T: Debug,
+    {
+        rust_literal(self.as_flat_array(), N, precision)
+    }
+}
+
+impl<T> DMatrix<T> {
+    /// Same as `Matrix::to_latex`.
+    #[must_use]
+    pub fn to_latex(&self, env: LatexEnvironment, precision: Option<usize>) -> String
+    where
+        T: Display,
+    {
+        latex(self.as_flat_slice(), self.cols(), env, precision)
+    }
+
+    /// Same as `Matrix::to_markdown`.
+    #[must_use]
+    pub fn to_markdown(&self, precision: Option<usize>) -> String
+    where
+        T: Display,
+    {
+        markdown(self.as_flat_slice(), self.cols(), precision)
+    }
+
+    /// Same as `Matrix::to_rust_literal`.
+    #[must_use]
+    pub fn to_rust_literal(&self, precision: Option<usize>) -> String
+    where
+        T: Debug,
+    {
+        rust_literal(self.as_flat_slice(), self.cols(), precision)
+    }
+}
+
+fn display_cell<T: Display>(elem: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{elem:.precision$}"),
+        None => elem.to_string(),
+    }
+}
+
+fn debug_cell<T: Debug>(elem: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{elem:.precision$?}"),
+        None => format!("{elem:?}"),
+    }
+}
+
+/// Splits row-major `data` into rows of formatted cells.
+fn rows<T, F>(data: &[T], cols: usize, cell: F) -> Vec<Vec<String>>
+where
+    F: Fn(&T) -> String,
+{
+    if cols == 0 {
+        return Vec::new();
+    }
+    data.chunks(cols)
+        .map(|row| row.iter().map(&cell).collect())
+        .collect()
+}
+
+fn latex<T: Display>(
+    data: &[T],
+    cols: usize,
+    env: LatexEnvironment,
+    precision: Option<usize>,
+) -> String {
+    let rows: Vec<String> = rows(data, cols, |elem| display_cell(elem, precision))
+        .into_iter()
+        .map(|row| row.join(" & "))
+        .collect();
+
+    let mut out = format!("\\begin{{{}}}\n", env.name());
+    if !rows.is_empty() {
+        out.push_str(&rows.join(" \\\\\n"));
+        out.push('\n');
+    }
+    let _ = write!(out, "\\end{{{}}}", env.name());
+    out
+}
+
+fn markdown<T: Display>(data: &[T], cols: usize, precision: Option<usize>) -> String {
+    let mut out = String::new();
+    let header: Vec<String> = (0..cols).map(|col| col.to_string()).collect();
+    let _ = writeln!(out, "| {} |", header.join(" | "));
+    let _ = writeln!(out, "|{}", " --: |".repeat(cols));
+    for row in rows(data, cols, |elem| display_cell(elem, precision)) {
+        let _ = writeln!(out, "| {} |", row.join(" | "));
+    }
+    out
+}
+
+fn rust_literal<T: Debug>(data: &[T], cols: usize, precision: Option<usize>) -> String {
+    let rows: Vec<String> = rows(data, cols, |elem| debug_cell(elem, precision))
+        .into_iter()
+        .map(|row| format!("[{}]", row.join(", ")))
+        .collect();
+    format!("qmat::matrix!([{}])", rows.join(", "))
+}
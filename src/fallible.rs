@@ -0,0 +1,202 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::ops::{Add, Mul};
+
+use crate::{
+    errors::{MatrixOperationError, NewMatrixError},
+    identities::Zero,
+    mat::{ColVector, Matrix, RowVector},
+    position::Position,
+};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    /// Gets a reference to the element at `pos`, or `None` if it's out of bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![0, 1, 2; 3, 4, 5];
+    /// assert_eq!(mat.get(Position(1, 2)), Some(&5));
+    /// assert_eq!(mat.get(Position(0, 3)), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        Self::in_bounds(pos).then(|| &self[pos])
+    }
+
+    /// Gets a mutable reference to the element at `pos`, or `None` if it's out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if Self::in_bounds(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Same as `get`, but returns an error saying where and how big the matrix is.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::OutOfBounds` if `pos` is out of bounds.
+    pub fn try_get(&self, pos: Position) -> Result<&T, MatrixOperationError> {
+        self.get(pos)
+            .ok_or(MatrixOperationError::OutOfBounds { pos, dims: (M, N) })
+    }
+
+    /// Same as `get_mut`, but returns an error saying where and how big the matrix is.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::OutOfBounds` if `pos` is out of bounds.
+    pub fn try_get_mut(&mut self, pos: Position) -> Result<&mut T, MatrixOperationError> {
+        self.get_mut(pos)
+            .ok_or(MatrixOperationError::OutOfBounds { pos, dims: (M, N) })
+    }
+
+    fn in_bounds(pos: Position) -> bool {
+        pos.0 < M && pos.1 < N
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy,
+{
+    /// Same as `get_row`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::OutOfBounds` if `row` is out of bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![0, 1; 2, 3];
+    /// assert_eq!(mat.try_get_row(1), Ok(row_vector![2, 3]));
+    /// assert!(mat.try_get_row(2).is_err());
+    /// ```
+    pub fn try_get_row(&self, row: usize) -> Result<RowVector<T, N>, MatrixOperationError> {
+        if row >= M {
+            return Err(MatrixOperationError::OutOfBounds {
+                pos: Position(row, 0),
+                dims: (M, N),
+            });
+        }
+        let data = self.as_flat_array();
+        Ok(Matrix::new(std::array::from_fn(|col| data[row * N + col]))?)
+    }
+
+    /// Same as `get_col`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::OutOfBounds` if `col` is out of bounds.
+    pub fn try_get_col(&self, col: usize) -> Result<ColVector<T, M>, MatrixOperationError> {
+        if col >= N {
+            return Err(MatrixOperationError::OutOfBounds {
+                pos: Position(0, col),
+                dims: (M, N),
+            });
+        }
+        let data = self.as_flat_array();
+        Ok(Matrix::new(std::array::from_fn(|row| data[row * N + col]))?)
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    /// Same as `multiply`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::IllegalOutput` if `RES_LEN` isn't `M * O`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = col_vector![5, 6];
+    /// assert_eq!(a.try_multiply::<1, 2, 2>(&b), Ok(col_vector![17, 39]));
+    /// assert!(a.try_multiply::<1, 2, 3>(&b).is_err());
+    /// ```
+    pub fn try_multiply<const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+    ) -> Result<Matrix<T, M, O, RES_LEN>, MatrixOperationError> {
+        if M * O != RES_LEN {
+            return Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: O,
+                len: RES_LEN,
+            }
+            .into());
+        }
+
+        let lhs = self.as_flat_array();
+        let rhs = other.as_flat_array();
+        Ok(Matrix::new(std::array::from_fn(|i| {
+            let (row, col) = (i / O, i % O);
+            (0..N).fold(T::zero(), |acc, j| {
+                acc + lhs[row * N + j] * rhs[j * O + col]
+            })
+        }))?)
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Copy + Mul<Output = T>,
+{
+    /// Same as `outer`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::IllegalOutput` if `RES_LEN` isn't `M * O`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = col_vector![1, 2];
+    /// let b = col_vector![3, 4];
+    /// assert_eq!(a.try_outer::<2, 4>(&b), Ok(matrix![3, 4; 6, 8]));
+    /// assert!(a.try_outer::<2, 5>(&b).is_err());
+    /// ```
+    pub fn try_outer<const O: usize, const RES_LEN: usize>(
+        &self,
+        other: &ColVector<T, O>,
+    ) -> Result<Matrix<T, M, O, RES_LEN>, MatrixOperationError> {
+        if M * O != RES_LEN {
+            return Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: O,
+                len: RES_LEN,
+            }
+            .into());
+        }
+
+        Ok(Matrix::new(std::array::from_fn(|i| {
+            self[i / O] * other[i % O]
+        }))?)
+    }
+}
+
+impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
+where
+    T: Zero + Copy,
+{
+    /// Same as `diag`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    /// * `NewMatrixError::IllegalGenerics` if `LEN` isn't `M * M`.
+    pub fn try_diag(val: T) -> Result<Self, NewMatrixError> {
+        if M * M != LEN {
+            return Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: M,
+                len: LEN,
+            });
+        }
+        let mut data = [T::zero(); LEN];
+        for i in 0..M {
+            data[i * M + i] = val;
+        }
+        Self::new(data)
+    }
+}
@@ -1,59 +1,149 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+//! The algebraic traits that matrix algorithms are bounded by.
+//!
+//! Each one is blanket-implemented from `num_traits`, so as well as the primitive numbers they cover
+//! `std::num::Wrapping`, `num_complex::Complex` and `num_rational::Ratio`:
+//!
+//! | trait       | primitive ints | floats | `Wrapping` | `Complex` | `Ratio` |
+//! | ----------- | :------------: | :----: | :--------: | :-------: | :-----: |
+//! | `Zero`      | ✓              | ✓      | ✓          | ✓         | ✓       |
+//! | `Identity`  | ✓              | ✓      | ✓          | ✓         | ✓       |
+//! | `Ring`      | ✓              | ✓      | ✓          | ✓         | ✓       |
+//! | `Field`     |                | ✓      |            | ✓         | ✓       |
+//! | `RealField` |                | ✓      |            |           |         |
+//! | `Magnitude` |                | ✓      |            | ✓         | ✓       |
+//!
+//! Unsigned ints count as rings even though subtraction can underflow, since that's what `num_traits::Num` does too.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_complex::Complex;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{Float, Inv, Signed};
+
 use crate::mat::Matrix;
 
+/// Defines a value where `x + T::zero() == x`.
+pub trait Zero {
+    #[must_use]
+    fn zero() -> Self;
+
+    /// Returns whether `self` is the additive identity.
+    fn is_zero(&self) -> bool;
+}
+
 /// Defines a value where `x * T::identity() == x`.
 pub trait Identity {
+    #[must_use]
     fn identity() -> Self;
 }
 
-impl<T: Default + Copy + Identity, const M: usize, const LEN: usize> Identity
+/// Values that can be added, subtracted and multiplied, with identities for addition and multiplication.
+pub trait Ring:
+    Copy + PartialEq + Zero + Identity + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+/// A ring where every nonzero value can be divided by, e.g. floats, complex numbers and rationals, but not ints.
+pub trait Field: Ring + Neg<Output = Self> + Div<Output = Self> + Inv<Output = Self> {}
+
+/// A field of ordered real numbers with the usual float functions, i.e. `f32` and `f64`.
+pub trait RealField: Field + PartialOrd + Float {}
+
+/// A size to compare values by, so that elimination can pivot on the biggest one
+/// instead of one small enough to blow up rounding errors.
+pub trait Magnitude {
+    type Output: PartialOrd;
+
+    /// Returns the absolute value, or the modulus for complex numbers.
+    fn magnitude(&self) -> Self::Output;
+}
+
+impl<T: num_traits::Zero> Zero for T {
+    fn zero() -> Self {
+        <T as num_traits::Zero>::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        <T as num_traits::Zero>::is_zero(self)
+    }
+}
+
+impl<T: num_traits::One> Identity for T {
+    fn identity() -> Self {
+        T::one()
+    }
+}
+
+impl<T> Ring for T where
+    T: Copy
+        + PartialEq
+        + Zero
+        + Identity
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+{
+}
+
+impl<T> Field for T where T: Ring + Neg<Output = Self> + Div<Output = Self> + Inv<Output = Self> {}
+
+impl<T> RealField for T where T: Field + PartialOrd + Float {}
+
+impl Magnitude for f32 {
+    type Output = Self;
+
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+}
+
+impl Magnitude for f64 {
+    type Output = Self;
+
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+}
+
+impl<T: Float> Magnitude for Complex<T> {
+    type Output = T;
+
+    fn magnitude(&self) -> T {
+        self.norm()
+    }
+}
+
+impl<T: Clone + Integer + Signed> Magnitude for Ratio<T> {
+    type Output = Self;
+
+    fn magnitude(&self) -> Self {
+        self.abs()
+    }
+}
+
+impl<T: Zero + Copy, const M: usize, const N: usize, const LEN: usize> Zero
+    for Matrix<T, M, N, LEN>
+{
+    /// # Panics
+    /// * If `LEN` isn't `M * N`
+    fn zero() -> Self {
+        Self::new([T::zero(); LEN]).expect("LEN must be M * N")
+    }
+
+    fn is_zero(&self) -> bool {
+        self.as_flat_array().iter().all(Zero::is_zero)
+    }
+}
+
+impl<T: Zero + Copy + Identity, const M: usize, const LEN: usize> Identity
     for Matrix<T, M, M, LEN>
 {
     /// # Panics
-    /// * If it fails to make an empty matrix
-    #[must_use]
+    /// * If `LEN` isn't `M * M`
     fn identity() -> Self {
         Self::diag(T::identity())
     }
 }
-
-macro_rules! id {
-    ($T:ty, $VAL:expr) => {
-        impl Identity for $T {
-            #[must_use]
-            fn identity() -> Self {
-                $VAL
-            }
-        }
-    };
-}
-
-/*
- * Numeric primitives
- */
-macro_rules! intid {
-    ($T:ty) => {
-        id!($T, 1);
-    };
-}
-
-// Signed integers
-intid!(i8);
-intid!(i16);
-intid!(i32);
-intid!(i64);
-intid!(i128);
-intid!(isize);
-
-// Unsigned integers
-intid!(u8);
-intid!(u16);
-intid!(u32);
-intid!(u64);
-intid!(u128);
-intid!(usize);
-
-// Floating point
-id!(f32, 1.0);
-id!(f64, 1.0);
@@ -1,24 +1,62 @@
 #![warn(clippy::all, clippy::pedantic)]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod complex;
+#[cfg(feature = "csv")]
+pub mod csv;
+/// Contains the heap-allocated `DMatrix` struct, whose dimensions are only known at runtime
+pub mod dmat;
 pub mod errors;
+pub mod export;
+mod fallible;
 /// Contains methods for getting identity matricies and scalars
 pub mod identities;
 /// Contains the `Matrix` struct and most associated methods
 pub mod mat;
+#[cfg(feature = "matfile")]
+pub mod matfile;
+pub mod modular;
+pub mod mtx;
+pub mod netpbm;
+/// Contains the `Mat` alias, which drops the `LEN` generic (requires the `nightly` feature)
+#[cfg(feature = "nightly")]
+pub mod nightly;
+#[cfg(feature = "npy")]
+pub mod npy;
+mod overflow;
 /// Contains the `Position` struct
 pub mod position;
+mod rational;
+pub mod semiring;
+pub mod serde;
 mod serialization;
+/// Contains the `CooMatrix` struct, for sparse matrices
+pub mod sparse;
+pub mod summation;
+mod text;
+mod vectors;
+mod widening;
 
 #[macro_use]
 mod new_matrix;
+#[doc(hidden)]
+pub use new_matrix::RowArray as __RowArray;
 
 mod math;
 
 // Convenience re-export of common members
 pub mod prelude {
+    pub use crate::col_vector;
+    pub use crate::dmat::DMatrix;
     pub use crate::identity;
-    pub use crate::mat::Matrix;
+    pub use crate::mat::{ColVector, Matrix, RowVector};
     pub use crate::matrix;
+    #[cfg(feature = "nightly")]
+    pub use crate::nightly::Mat;
     pub use crate::position::Position;
+    pub use crate::row_vector;
     pub use crate::vector;
 }
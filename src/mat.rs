@@ -2,12 +2,12 @@
 
 use std::{
     iter::Sum,
-    ops::{Add, Index, IndexMut, Mul, Sub},
+    ops::{Add, Div, Index, IndexMut, Mul, Sub},
 };
 
 use crate::{
     errors::{MatrixOperationError, NewMatrixError},
-    identities::Identity,
+    identities::{Field, Magnitude, Ring, Zero},
     math::arr_dot,
     position::Position,
 };
@@ -15,10 +15,17 @@ use crate::{
 /// A matrix of `M` rows and `N` columns. <br/>
 /// `LEN` is the length of the internal array `data: [T; LEN]` that stores all the elements (i.e. `LEN` = `M` * `N`).
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
 pub struct Matrix<T, const M: usize, const N: usize, const LEN: usize> {
     data: [T; LEN],
 }
 
+/// A matrix with a single row of `N` elements.
+pub type RowVector<T, const N: usize> = Matrix<T, 1, N, N>;
+
+/// A matrix with a single column of `M` elements.
+pub type ColVector<T, const M: usize> = Matrix<T, M, 1, M>;
+
 impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
     /// Creates a new Matrix from given dimensions and flat data.
     ///
@@ -33,7 +40,11 @@ impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
     /// ```
     pub fn new(data: [T; LEN]) -> Result<Self, NewMatrixError> {
         if M * N != LEN {
-            return Err(NewMatrixError::IllegalGenerics);
+            return Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: N,
+                len: LEN,
+            });
         }
         Ok(Matrix { data })
     }
@@ -49,6 +60,14 @@ impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
         &self.data
     }
 
+    /// Consumes the matrix, returning the underlying 1-dimensional data.
+    ///
+    /// Flattened in the same way as `Matrix::as_flat_array`.
+    #[must_use]
+    pub fn into_flat_array(self) -> [T; LEN] {
+        self.data
+    }
+
     /// Returns the constant number of rows, `M`.
     #[must_use]
     #[allow(clippy::unused_self)] // so you can call someMatrix.rows()
@@ -71,7 +90,7 @@ impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
     }
 
     /// Iterates over immutable references to all of the elements of a matrix.
-    pub fn iter(&self) -> Iter<T, M, N, LEN> {
+    pub fn iter(&self) -> Iter<'_, T, M, N, LEN> {
         Iter {
             data: &self.data,
             i: 0,
@@ -147,80 +166,105 @@ where
     pub fn empty() -> Result<Self, NewMatrixError> {
         Self::new([Default::default(); LEN])
     }
+}
 
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy,
+{
     /// Errors
     /// * Same as `Matrix::new`
     #[allow(clippy::missing_errors_doc)] // idk why clippy can't read
     pub fn from_rows(data: [[T; N]; M]) -> Result<Self, NewMatrixError> {
-        let mut flat_data: [T; LEN] = [Default::default(); LEN];
-        let mut i: usize = 0;
-        for row in data {
-            for elem in row {
-                flat_data[i] = elem;
-                i += 1;
-            }
+        match data.as_flattened().try_into() {
+            Ok(flat_data) => Self::new(flat_data),
+            Err(_) => Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: N,
+                len: LEN,
+            }),
         }
-        Self::new(flat_data)
     }
 
-    /// Gets a specific row of the matrix.
-    ///  
+    /// Gets a specific row of the matrix, as a row vector.
+    /// See `try_get_row` for a version that doesn't panic.
+    ///
     /// # Panics
-    /// * When it fails to make an empty matrix.
+    /// * If `row` is out of bounds.
     ///
     /// # Examples
     /// ```rust
     /// use qmat::prelude::*;
     /// let mat = Matrix::<_, 2, 2, 4>::new([0, 1, 2, 3]).unwrap(); // [[0, 1], [2, 3]]
-    /// assert_eq!(mat.get_row(0)[[0, 0]], 0);
-    /// assert_eq!(mat.get_row(0)[[0, 1]], 1);
-    /// assert_eq!(mat.get_row(1)[[0, 0]], 2);
-    /// assert_eq!(mat.get_row(1)[[0, 1]], 3);
+    /// assert_eq!(mat.get_row(0)[0], 0);
+    /// assert_eq!(mat.get_row(0)[1], 1);
+    /// assert_eq!(mat.get_row(1)[0], 2);
+    /// assert_eq!(mat.get_row(1)[1], 3);
     /// ```
     #[must_use]
-    pub fn get_row(&self, row: usize) -> Matrix<T, N, 1, N> {
-        let mut output = Matrix::empty().unwrap();
-        for i in 0..N {
-            output.data[i] = self.data[row * N + i];
+    pub fn get_row(&self, row: usize) -> RowVector<T, N> {
+        assert!(row < M, "row index out of bounds");
+        Matrix {
+            data: std::array::from_fn(|i| self.data[row * N + i]),
         }
-        output
     }
 
-    /// Gets a specific column of the matrix.
-    ///  
+    /// Gets a specific column of the matrix, as a column vector.
+    /// See `try_get_col` for a version that doesn't panic.
+    ///
     /// # Panics
-    /// * When it fails to make an empty matrix.
+    /// * If `col` is out of bounds.
     ///
     /// # Examples
     /// ```rust
     /// use qmat::prelude::*;
     /// let mat = Matrix::<_, 2, 2, 4>::new([0, 1, 2, 3]).unwrap(); // [[0, 1], [2, 3]]
-    /// assert_eq!(mat.get_col(0)[[0, 0]], 0);
-    /// assert_eq!(mat.get_col(0)[[0, 1]], 2);
-    /// assert_eq!(mat.get_col(1)[[0, 0]], 1);
-    /// assert_eq!(mat.get_col(1)[[0, 1]], 3);
+    /// assert_eq!(mat.get_col(0)[0], 0);
+    /// assert_eq!(mat.get_col(0)[1], 2);
+    /// assert_eq!(mat.get_col(1)[0], 1);
+    /// assert_eq!(mat.get_col(1)[1], 3);
+    /// ```
+    #[must_use]
+    pub fn get_col(&self, col: usize) -> ColVector<T, M> {
+        assert!(col < N, "column index out of bounds");
+        Matrix {
+            data: std::array::from_fn(|row| self.data[row * N + col]),
+        }
+    }
+
+    /// Swaps the rows and columns of the matrix, so that `[i, j]` becomes `[j, i]`.
+    /// A row vector becomes a column vector and vice versa.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix!([[0, 1, 2], [3, 4, 5]]);
+    /// let transposed = mat.transpose();
+    /// assert_eq!(transposed.rows(), 3);
+    /// assert_eq!(transposed[[2, 1]], 5);
     /// ```
     #[must_use]
-    pub fn get_col(&self, col: usize) -> Matrix<T, M, 1, M> {
-        let mut output = Matrix::empty().unwrap();
-        for row in 0..M {
-            output.data[row] = self[[row, col]];
+    pub fn transpose(&self) -> Matrix<T, N, M, LEN> {
+        // element `i` of the output is at `[i / M, i % M]`, which is `[i % M, i / M]` in `self`
+        Matrix {
+            data: std::array::from_fn(|i| self.data[(i % M) * N + i / M]),
         }
-        output
     }
 }
 
 impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
 where
-    T: Default + Copy + Mul + Sum<<T as Mul>::Output>,
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
 {
     /// Turbofish `::<O, Q, RES_LEN>` where
     /// * `O` is the number of columns in the other matrix,
     /// * `Q` is the array length in the other matrix,
     /// * `RES_LEN` is the number of elements in the resulting matrix (`M` * `O`) where `M` is rows in `self`
     ///
+    /// See `try_multiply` for a version that doesn't panic.
+    ///
     /// # Panics
-    /// * When it fails to make an empty matrix.
+    /// * If `RES_LEN` isn't `M * O`.
     ///
     /// # Examples
     /// ```rust
@@ -239,21 +283,14 @@ where
         &self,
         other: &Matrix<T, N, O, Q>,
     ) -> Matrix<T, M, O, RES_LEN> {
-        let mut out: Matrix<T, M, O, RES_LEN> = Matrix::empty().unwrap();
-
-        for row in 0..N {
-            for col in 0..O {
-                out[[row, col]] = self.get_row(row).dot(&other.get_col(col));
-            }
-        }
-
-        out
+        self.try_multiply(other)
+            .expect("RES_LEN must be the number of elements in the product")
     }
 }
 
 impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
 where
-    T: Default + Copy + Mul<Output = T>,
+    T: Copy + Mul<Output = T>,
 {
     /// Multiplies a matrix with and scalar value.
     /// Iterates over all elements in the matrix and multiplies it by the given scalar.
@@ -267,16 +304,11 @@ where
     /// assert_eq!(res[[0, 1]], 3);
     /// assert_eq!(res[[0, 2]], 6);
     /// ```
-    ///
-    /// # Panics
-    /// * When it fails to make an empty matrix.
     #[must_use]
     pub fn mul_scalar(&self, scalar: T) -> Self {
-        let mut out = Matrix::empty().unwrap();
-        for i in 0..LEN {
-            out.data[i] = self.data[i] * scalar;
+        Matrix {
+            data: self.data.map(|x| x * scalar),
         }
-        out
     }
 }
 
@@ -337,9 +369,61 @@ impl<T, const M: usize, const N: usize, const LEN: usize> IndexMut<[usize; 2]>
     }
 }
 
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    const ASSERT_VECTOR: () = assert!(
+        M == 1 || N == 1,
+        "single-index access requires a row or column vector"
+    );
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Index<usize> for Matrix<T, M, N, LEN> {
+    type Output = T;
+
+    /// Indexes into a row or column vector with a single index.
+    ///
+    /// Using it on a matrix that isn't a row or column vector fails to compile:
+    /// ```compile_fail
+    /// use qmat::prelude::*;
+    /// let mat = matrix![0, 1; 2, 3];
+    /// let x = mat[1];
+    /// ```
+    ///
+    /// # Panics
+    /// * If the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use qmat::prelude::*;
+    /// let row = row_vector![0, 1, 2];
+    /// let col = col_vector![3, 4, 5];
+    /// assert_eq!(row[1], 1);
+    /// assert_eq!(col[1], 4);
+    /// ```
+    fn index(&self, i: usize) -> &Self::Output {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_VECTOR;
+        &self.data[i]
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> IndexMut<usize> for Matrix<T, M, N, LEN> {
+    /// # Examples
+    /// ```
+    /// use qmat::prelude::*;
+    /// let mut col = col_vector![3, 4, 5];
+    /// col[2] = 12;
+    /// assert_eq!(col[[2, 0]], 12);
+    /// ```
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_VECTOR;
+        &mut self.data[i]
+    }
+}
+
 impl<T, const M: usize, const N: usize, const LEN: usize> Add for Matrix<T, M, N, LEN>
 where
-    T: Add<Output = T> + Default + Copy,
+    T: Add<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -359,17 +443,13 @@ where
     /// assert_eq!(added[[1, 1]], 8); // 5 + 3
     /// ```
     fn add(self, rhs: Self) -> Self::Output {
-        let mut added = Self::empty().unwrap();
-
-        for i in 0..LEN {
-            added.data[i] = self.data[i] + rhs.data[i];
+        Matrix {
+            data: std::array::from_fn(|i| self.data[i] + rhs.data[i]),
         }
-
-        added
     }
 }
 
-impl<T, const M: usize> Matrix<T, M, 1, M>
+impl<T, const M: usize> ColVector<T, M>
 where
     T: Copy + Mul + Sum<<T as Mul>::Output>,
 {
@@ -391,7 +471,7 @@ where
 
 impl<T, const M: usize, const N: usize, const LEN: usize> Sub for Matrix<T, M, N, LEN>
 where
-    T: Sub<Output = T> + Default + Copy,
+    T: Sub<Output = T> + Copy,
 {
     type Output = Self;
 
@@ -409,17 +489,15 @@ where
     /// assert_eq!(subbed[[1, 1]], 2);   // 5 - 3
     /// ```
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut subbed = Self::empty().unwrap();
-        for i in 0..LEN {
-            subbed.data[i] = self.data[i] - rhs.data[i];
+        Matrix {
+            data: std::array::from_fn(|i| self.data[i] - rhs.data[i]),
         }
-        subbed
     }
 }
 
 impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
 where
-    T: Default + Copy,
+    T: Zero + Copy,
 {
     /// Creates a new matrix such that every value in the diagonal from the top left (`[0, 0]`) to the bottom left (`[M, M]`) are equal to `val`.
     ///
@@ -434,22 +512,16 @@ where
     /// ```
     ///
     /// # Panics
-    /// * If it fails to create an empty matrix.
+    /// * If `LEN` isn't `M * M`. See `try_diag` for a version that doesn't panic.
     #[must_use]
     pub fn diag(val: T) -> Self {
-        let mut mat = Self::empty().unwrap();
-
-        for i in 0..M {
-            mat[[i, i]] = val;
-        }
-
-        mat
+        Self::try_diag(val).expect("LEN must be M * M")
     }
 }
 
 impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
 where
-    T: num_traits::Num + Copy,
+    T: Ring + Div<Output = T>,
 {
     /// Returns the [determinant](https://en.wikipedia.org/wiki/Determinant) of a matrix.
     #[must_use]
@@ -457,15 +529,15 @@ where
         let mat = self;
         let mut mat = *mat; // hopefully dereferences a copy of self?
         let mut temp = [T::zero(); N]; // temp array for row storage
-        let mut total: T = T::one();
-        let mut det: T = T::one(); // init res
+        let mut total: T = T::identity();
+        let mut det: T = T::identity(); // init res
 
         // loop for traversing diagonal elems
         for i in 0..N {
             let mut index = i;
 
             // finding non-zero value
-            while index < N && self[[index, i]] == T::zero() {
+            while index < N && mat[[index, i]] == T::zero() {
                 index += 1;
             }
 
@@ -480,11 +552,8 @@ where
                     (mat[[index, j]], mat[[i, j]]) = (mat[[i, j]], mat[[index, j]]);
                 }
 
-                // det sign changes when row is shifted
-                let exp = index - i;
-                if exp % 2 == 1 {
-                    det = det * (T::zero() - T::one());
-                }
+                // det sign changes when two rows are swapped
+                det = det * (T::zero() - T::identity());
             }
 
             // storing diagonal row elems
@@ -517,14 +586,34 @@ where
 
 impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
 where
-    T: num_traits::Num + Copy + Default + Identity,
+    T: Field + Magnitude,
 {
+    /// Returns the [inverse](https://en.wikipedia.org/wiki/Invertible_matrix) of a matrix.
+    ///
+    /// Matrices bigger than 2x2 use Gauss-Jordan elimination with partial pivoting,
+    /// picking the element of each column with the biggest `Magnitude` as its pivot.
+    /// That keeps it exact for types like `num_rational::Ratio`,
+    /// but floats can still lose precision on badly conditioned matrices.
+    /// Integers aren't a `Field`, so integer matrices have to use `inverse_exact` instead.
+    ///
     /// # Errors
-    /// * `MatrixOperationError::InvalidDeterminant` is `self.det() == 0`.
+    /// * `MatrixOperationError::Singular` if `self.det() == 0`.
     ///
     /// # Panics
-    /// * If it fails to create an empty matrix.
-    /// * When trying to get the inverse of a matrix that isn't 2x2. (Currently unimplemented.)
+    /// * If `LEN` isn't `M * M`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![2.0, 0.0, 0.0; 0.0, 0.0, 4.0; 0.0, 1.0, 0.0];
+    /// assert_eq!(mat.inverse(), Ok(matrix![0.5, 0.0, 0.0; 0.0, 0.0, 1.0; 0.0, 0.25, 0.0]));
+    /// ```
+    ///
+    /// Integer division would truncate, so integer matrices don't have an `inverse`:
+    /// ```compile_fail
+    /// use qmat::prelude::*;
+    /// let inv = matrix![2, 1, 0; 1, 1, 0; 0, 0, 1].inverse();
+    /// ```
     pub fn inverse(&self) -> Result<Self, MatrixOperationError> {
         match M {
             2 => self.inverse_2x2(),
@@ -535,24 +624,119 @@ where
     fn inverse_2x2(&self) -> Result<Self, MatrixOperationError> {
         let det = self.det();
         if det.is_zero() {
-            return Err(MatrixOperationError::InvalidDeterminant);
+            return Err(MatrixOperationError::Singular);
         }
 
-        let min1 = T::zero() - T::one();
+        let min1 = T::zero() - T::identity();
 
-        let mut augmented = Self::empty().unwrap();
+        let mut augmented = *self;
         augmented.data[0] = self.data[3]; // a
         augmented.data[1] = self.data[1] * min1; // b
         augmented.data[2] = self.data[2] * min1; // c
         augmented.data[3] = self.data[0]; // d
 
-        Ok(augmented.mul_scalar(T::one() / det))
+        Ok(augmented.mul_scalar(T::identity() / det))
     }
 
     /// <https://www.mathsisfun.com/algebra/matrix-inverse-row-operations-gauss-jordan.html>
     /// <https://www.codesansar.com/numerical-methods/python-program-inverse-matrix-using-gauss-jordan.htm>
     fn inverse_gauss_jordan(&self) -> Result<Self, MatrixOperationError> {
-        todo!();
+        let mut mat = *self;
+        let mut inv = Self::diag(T::identity());
+
+        for col in 0..M {
+            let mut pivot = col;
+            for row in col + 1..M {
+                if mat[[row, col]].magnitude() > mat[[pivot, col]].magnitude() {
+                    pivot = row;
+                }
+            }
+            if mat[[pivot, col]].is_zero() {
+                return Err(MatrixOperationError::Singular);
+            }
+            if pivot != col {
+                for j in 0..M {
+                    (mat[[col, j]], mat[[pivot, j]]) = (mat[[pivot, j]], mat[[col, j]]);
+                    (inv[[col, j]], inv[[pivot, j]]) = (inv[[pivot, j]], inv[[col, j]]);
+                }
+            }
+
+            // scale the pivot row so the pivot is 1
+            let scale = mat[[col, col]];
+            for j in 0..M {
+                mat[[col, j]] = mat[[col, j]] / scale;
+                inv[[col, j]] = inv[[col, j]] / scale;
+            }
+
+            // eliminate the column from every other row
+            for row in (0..M).filter(|&row| row != col) {
+                let factor = mat[[row, col]];
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in 0..M {
+                    mat[[row, j]] = mat[[row, j]] - factor * mat[[col, j]];
+                    inv[[row, j]] = inv[[row, j]] - factor * inv[[col, j]];
+                }
+            }
+        }
+
+        Ok(inv)
+    }
+
+    /// Solves `self * x = b` for `x`, by Gaussian elimination with partial pivoting.
+    /// Like `inverse`, it picks the element of each column with the biggest `Magnitude` as its pivot,
+    /// so it's exact over fields like `num_rational::Ratio` and `modular::Gf`.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::Singular` if the matrix is singular.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![2.0, 1.0; 1.0, 3.0];
+    /// let b = col_vector![3.0, 5.0];
+    /// assert_eq!(mat.solve(&b), Ok(col_vector![0.8, 1.4]));
+    /// ```
+    pub fn solve(&self, b: &ColVector<T, M>) -> Result<ColVector<T, M>, MatrixOperationError> {
+        let mut mat = *self;
+        let mut x = *b;
+
+        for col in 0..M {
+            let mut pivot = col;
+            for row in col + 1..M {
+                if mat[[row, col]].magnitude() > mat[[pivot, col]].magnitude() {
+                    pivot = row;
+                }
+            }
+            if mat[[pivot, col]].is_zero() {
+                return Err(MatrixOperationError::Singular);
+            }
+            if pivot != col {
+                for j in col..M {
+                    (mat[[col, j]], mat[[pivot, j]]) = (mat[[pivot, j]], mat[[col, j]]);
+                }
+                (x[col], x[pivot]) = (x[pivot], x[col]);
+            }
+
+            for row in col + 1..M {
+                let factor = mat[[row, col]] / mat[[col, col]];
+                for j in col..M {
+                    mat[[row, j]] = mat[[row, j]] - factor * mat[[col, j]];
+                }
+                x[row] = x[row] - factor * x[col];
+            }
+        }
+
+        // back substitution
+        for row in (0..M).rev() {
+            let mut sum = x[row];
+            for j in row + 1..M {
+                sum = sum - mat[[row, j]] * x[j];
+            }
+            x[row] = sum / mat[[row, row]];
+        }
+        Ok(x)
     }
 }
 
@@ -571,6 +755,28 @@ where
     }
 }
 
+impl<'a, T, const M: usize, const N: usize, const LEN: usize> IntoIterator
+    for &'a Matrix<T, M, N, LEN>
+{
+    type IntoIter = Iter<'a, T, M, N, LEN>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize, const LEN: usize> IntoIterator
+    for &'a mut Matrix<T, M, N, LEN>
+{
+    type IntoIter = std::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[derive(Debug)]
 pub struct IntoIter<T, const M: usize, const N: usize, const LEN: usize> {
     i: usize,
@@ -619,28 +825,20 @@ pub struct IntoIterRow<T, const M: usize, const N: usize, const LEN: usize> {
 }
 impl<T, const M: usize, const N: usize, const LEN: usize> Iterator for IntoIterRow<T, M, N, LEN>
 where
-    T: Copy + Default,
+    T: Copy,
 {
-    type Item = Matrix<T, M, 1, M>;
+    type Item = RowVector<T, N>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= N {
+        if self.i >= M {
             return None;
         }
 
-        let offset = self.i * M;
-        let mut row: [T; M] = [Default::default(); M];
-
-        for (j, elem) in row.iter_mut().enumerate() {
-            *elem = self.data[offset + j];
-        }
+        let offset = self.i * N;
+        let row: [T; N] = std::array::from_fn(|j| self.data[offset + j]);
 
         self.i += 1;
 
-        if let Ok(mat) = Matrix::<T, M, 1, M>::new(row) {
-            Some(mat)
-        } else {
-            None
-        }
+        RowVector::<T, N>::new(row).ok()
     }
 }
 
@@ -653,14 +851,14 @@ impl<'a, T, const M: usize, const N: usize, const LEN: usize> Iterator for IterR
 where
     T: 'a,
 {
-    type Item = Matrix<&'a T, M, 1, M>;
+    type Item = RowVector<&'a T, N>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= N {
+        if self.i >= M {
             return None;
         }
 
-        let offset = self.i * M;
-        let mut row: [&'a T; M] = [&self.data[offset]; M];
+        let offset = self.i * N;
+        let mut row: [&'a T; N] = [&self.data[offset]; N];
 
         for (j, elem) in row.iter_mut().enumerate() {
             *elem = &self.data[offset + j];
@@ -668,11 +866,7 @@ where
 
         self.i += 1;
 
-        if let Ok(mat) = Matrix::<&T, M, 1, M>::new(row) {
-            Some(mat)
-        } else {
-            None
-        }
+        RowVector::<&T, N>::new(row).ok()
     }
 }
 
@@ -683,27 +877,19 @@ pub struct IntoIterCol<T, const M: usize, const N: usize, const LEN: usize> {
 }
 impl<T, const M: usize, const N: usize, const LEN: usize> Iterator for IntoIterCol<T, M, N, LEN>
 where
-    T: Copy + Default,
+    T: Copy,
 {
-    type Item = Matrix<T, 1, N, N>;
+    type Item = ColVector<T, M>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= M {
+        if self.i >= N {
             return None;
         }
 
-        let mut col: [T; N] = [Default::default(); N];
-
-        for (j, elem) in col.iter_mut().enumerate() {
-            *elem = self.data[M * j + self.i];
-        }
+        let col: [T; M] = std::array::from_fn(|j| self.data[N * j + self.i]);
 
         self.i += 1;
 
-        if let Ok(mat) = Matrix::<T, 1, N, N>::new(col) {
-            Some(mat)
-        } else {
-            None
-        }
+        ColVector::<T, M>::new(col).ok()
     }
 }
 
@@ -716,23 +902,19 @@ impl<'a, T, const M: usize, const N: usize, const LEN: usize> Iterator for IterC
 where
     T: 'a,
 {
-    type Item = Matrix<&'a T, 1, N, N>;
+    type Item = ColVector<&'a T, M>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.i >= N {
             return None;
         }
 
-        let mut col: [&'a T; N] = [&self.data[M + self.i]; N];
+        let mut col: [&'a T; M] = [&self.data[self.i]; M];
         for (j, elem) in col.iter_mut().enumerate() {
-            *elem = &self.data[M * j + self.i];
+            *elem = &self.data[N * j + self.i];
         }
 
         self.i += 1;
 
-        if let Ok(mat) = Matrix::<&T, 1, N, N>::new(col) {
-            Some(mat)
-        } else {
-            None
-        }
+        ColVector::<&T, M>::new(col).ok()
     }
 }
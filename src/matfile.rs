@@ -0,0 +1,564 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Reading and writing MATLAB/Octave Level 5 `.mat` files (requires the `matfile` feature).
+//!
+//! Only named, real, 2-dimensional numeric arrays are supported. Other variables (e.g. cells, structs,
+//! strings, sparse or complex arrays) are kept track of when reading so that asking for them gives a
+//! clear error, but they're dropped when writing.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{matfile::MatFile, prelude::*};
+//!
+//! let mut file = MatFile::new();
+//! file.insert("A", matrix![1.0, 2.0; 3.0, 4.0]);
+//! file.insert("counts", DMatrix::new(1, 3, vec![1u8, 2, 3]).unwrap());
+//!
+//! let mut buf = Vec::new();
+//! file.write(&mut buf, true).unwrap();
+//!
+//! let read = MatFile::read(buf.as_slice()).unwrap();
+//! let a: Matrix<f64, 2, 2, 4> = read.get_matrix("A").unwrap();
+//! assert_eq!(a, matrix![1.0, 2.0; 3.0, 4.0]);
+//! assert_eq!(read.get::<u8>("counts").unwrap().as_flat_slice(), &[1, 2, 3]);
+//! ```
+
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use num_traits::NumCast;
+
+use crate::{dmat::DMatrix, errors::MatFileError, mat::Matrix};
+
+const MI_INT8: u32 = 1;
+const MI_UINT8: u32 = 2;
+const MI_INT16: u32 = 3;
+const MI_UINT16: u32 = 4;
+const MI_INT32: u32 = 5;
+const MI_UINT32: u32 = 6;
+const MI_SINGLE: u32 = 7;
+const MI_DOUBLE: u32 = 9;
+const MI_INT64: u32 = 12;
+const MI_UINT64: u32 = 13;
+const MI_MATRIX: u32 = 14;
+const MI_COMPRESSED: u32 = 15;
+
+const FLAG_COMPLEX: u32 = 0x0800;
+
+/// The MATLAB class of a numeric array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatClass {
+    Double,
+    Single,
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Int64,
+    UInt64,
+}
+
+impl MatClass {
+    fn from_id(id: u32) -> Option<Self> {
+        Some(match id {
+            6 => Self::Double,
+            7 => Self::Single,
+            8 => Self::Int8,
+            9 => Self::UInt8,
+            10 => Self::Int16,
+            11 => Self::UInt16,
+            12 => Self::Int32,
+            13 => Self::UInt32,
+            14 => Self::Int64,
+            15 => Self::UInt64,
+            _ => return None,
+        })
+    }
+
+    fn id(self) -> u32 {
+        match self {
+            Self::Double => 6,
+            Self::Single => 7,
+            Self::Int8 => 8,
+            Self::UInt8 => 9,
+            Self::Int16 => 10,
+            Self::UInt16 => 11,
+            Self::Int32 => 12,
+            Self::UInt32 => 13,
+            Self::Int64 => 14,
+            Self::UInt64 => 15,
+        }
+    }
+}
+
+impl fmt::Display for MatClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Double => "double",
+            Self::Single => "single",
+            Self::Int8 => "int8",
+            Self::UInt8 => "uint8",
+            Self::Int16 => "int16",
+            Self::UInt16 => "uint16",
+            Self::Int32 => "int32",
+            Self::UInt32 => "uint32",
+            Self::Int64 => "int64",
+            Self::UInt64 => "uint64",
+        })
+    }
+}
+
+/// Element types that can be read from and written to `.mat` files.
+pub trait MatElement: Copy + NumCast {
+    /// The class of arrays of this type.
+    const CLASS: MatClass;
+
+    /// The data type the elements are written as.
+    const MI_TYPE: u32;
+
+    /// The size of an element in bytes.
+    const SIZE: usize = std::mem::size_of::<Self>();
+
+    /// Reads an element from exactly `SIZE` bytes.
+    fn from_mat_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// Appends the little-endian bytes of the element.
+    fn write_mat_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! mat_element {
+    ($T:ty, $CLASS:ident, $MI_TYPE:ident) => {
+        impl MatElement for $T {
+            const CLASS: MatClass = MatClass::$CLASS;
+            const MI_TYPE: u32 = $MI_TYPE;
+
+            fn from_mat_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let mut buf = [0; std::mem::size_of::<$T>()];
+                buf.copy_from_slice(bytes);
+                if big_endian {
+                    <$T>::from_be_bytes(buf)
+                } else {
+                    <$T>::from_le_bytes(buf)
+                }
+            }
+
+            fn write_mat_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+mat_element!(i8, Int8, MI_INT8);
+mat_element!(u8, UInt8, MI_UINT8);
+mat_element!(i16, Int16, MI_INT16);
+mat_element!(u16, UInt16, MI_UINT16);
+mat_element!(i32, Int32, MI_INT32);
+mat_element!(u32, UInt32, MI_UINT32);
+mat_element!(i64, Int64, MI_INT64);
+mat_element!(u64, UInt64, MI_UINT64);
+mat_element!(f32, Single, MI_SINGLE);
+mat_element!(f64, Double, MI_DOUBLE);
+
+/// A numeric array. The elements are kept as column-major, little-endian bytes of `mi_type`,
+/// which may be smaller than the class (MATLAB stores e.g. small doubles as `miUINT8`).
+#[derive(Debug, Clone, PartialEq)]
+struct Variable {
+    name: String,
+    class: MatClass,
+    rows: usize,
+    cols: usize,
+    mi_type: u32,
+    data: Vec<u8>,
+}
+
+/// The variables in a `.mat` file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MatFile {
+    variables: Vec<Variable>,
+    unsupported: Vec<String>,
+}
+
+impl MatFile {
+    /// Creates a file with no variables.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the names of the numeric matrices, in the order they're stored.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.variables.iter().map(|var| var.name.as_str())
+    }
+
+    /// Returns the class of the named matrix, if there is one.
+    #[must_use]
+    pub fn class(&self, name: &str) -> Option<MatClass> {
+        self.find(name).map(|var| var.class)
+    }
+
+    /// Adds a matrix, replacing any variable with the same name.
+    pub fn insert<T, D>(&mut self, name: &str, mat: D)
+    where
+        T: MatElement,
+        D: Into<DMatrix<T>>,
+    {
+        let mat = mat.into();
+        let mut data = Vec::with_capacity(mat.vol() * T::SIZE);
+        for col in 0..mat.cols() {
+            for row in 0..mat.rows() {
+                mat[[row, col]].write_mat_bytes(&mut data);
+            }
+        }
+
+        let var = Variable {
+            name: name.to_string(),
+            class: T::CLASS,
+            rows: mat.rows(),
+            cols: mat.cols(),
+            mi_type: T::MI_TYPE,
+            data,
+        };
+        self.unsupported.retain(|other| other != name);
+        match self.variables.iter_mut().find(|other| other.name == name) {
+            Some(other) => *other = var,
+            None => self.variables.push(var),
+        }
+    }
+
+    /// Gets the named matrix.
+    ///
+    /// # Errors
+    /// * `MatFileError::NotFound` if there's no variable with that name.
+    /// * `MatFileError::UnsupportedArray` if the variable isn't a real, 2-dimensional numeric array.
+    /// * `MatFileError::ClassMismatch` if the variable's class isn't `T`'s.
+    /// * `MatFileError::InvalidElement` if an element can't be represented in `T`.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    pub fn get<T: MatElement>(&self, name: &str) -> Result<DMatrix<T>, MatFileError> {
+        let Some(var) = self.find(name) else {
+            return Err(if self.unsupported.iter().any(|other| other == name) {
+                MatFileError::UnsupportedArray(name.to_string())
+            } else {
+                MatFileError::NotFound(name.to_string())
+            });
+        };
+        if var.class != T::CLASS {
+            return Err(MatFileError::ClassMismatch {
+                name: name.to_string(),
+                expected: T::CLASS,
+                found: var.class,
+            });
+        }
+
+        let col_major: Vec<T> = decode(var.mi_type, &var.data, false).ok_or_else(|| {
+            MatFileError::InvalidElement(format!("`{name}` has elements that don't fit its class"))
+        })?;
+        let mut data = Vec::with_capacity(col_major.len());
+        for row in 0..var.rows {
+            for col in 0..var.cols {
+                data.push(col_major[col * var.rows + row]);
+            }
+        }
+        Ok(DMatrix::new(var.rows, var.cols, data).unwrap())
+    }
+
+    /// Same as `get`, but for a fixed-size matrix.
+    ///
+    /// # Errors
+    /// * `MatFileError::DimensionMismatch` if the variable isn't `M` by `N`.
+    /// * Same as `get`.
+    pub fn get_matrix<T, const M: usize, const N: usize, const LEN: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Matrix<T, M, N, LEN>, MatFileError>
+    where
+        T: MatElement,
+    {
+        let mat = self.get::<T>(name)?;
+        let found = (mat.rows(), mat.cols());
+        mat.try_into().map_err(|_| MatFileError::DimensionMismatch {
+            expected: (M, N),
+            found,
+        })
+    }
+
+    fn find(&self, name: &str) -> Option<&Variable> {
+        self.variables.iter().find(|var| var.name == name)
+    }
+
+    /// Reads a `.mat` file, including compressed (v7) ones.
+    ///
+    /// # Errors
+    /// * `MatFileError::Io` if reading or decompressing fails.
+    /// * `MatFileError::InvalidHeader` if it isn't a Level 5 MAT-file.
+    /// * `MatFileError::InvalidElement` if a data element is malformed.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, MatFileError> {
+        let mut header = [0; 128];
+        reader.read_exact(&mut header)?;
+        if !header.starts_with(b"MATLAB") {
+            return Err(MatFileError::InvalidHeader);
+        }
+        let big_endian = match &header[126..] {
+            b"IM" => false,
+            b"MI" => true,
+            _ => return Err(MatFileError::InvalidHeader),
+        };
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        let mut file = Self::new();
+        let mut elements = Elements {
+            bytes: &bytes,
+            big_endian,
+        };
+        while let Some((mi_type, data)) = elements.next_element()? {
+            match mi_type {
+                MI_MATRIX => file.read_matrix(data, big_endian)?,
+                MI_COMPRESSED => {
+                    let mut decompressed = Vec::new();
+                    ZlibDecoder::new(data).read_to_end(&mut decompressed)?;
+                    let mut inner = Elements {
+                        bytes: &decompressed,
+                        big_endian,
+                    };
+                    while let Some((mi_type, data)) = inner.next_element()? {
+                        if mi_type == MI_MATRIX {
+                            file.read_matrix(data, big_endian)?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(file)
+    }
+
+    fn read_matrix(&mut self, bytes: &[u8], big_endian: bool) -> Result<(), MatFileError> {
+        let mut elements = Elements { bytes, big_endian };
+        let mut next = |what: &str| {
+            elements
+                .next_element()?
+                .ok_or_else(|| MatFileError::InvalidElement(format!("array is missing its {what}")))
+        };
+
+        let (_, flags) = next("flags")?;
+        let Some(&[flags, ..]) = decode::<u32>(MI_UINT32, flags, big_endian).as_deref() else {
+            return Err(MatFileError::InvalidElement(
+                "invalid array flags".to_string(),
+            ));
+        };
+        let (dims_type, dims) = next("dimensions")?;
+        let dims: Vec<usize> = decode::<u32>(dims_type, dims, big_endian)
+            .ok_or_else(|| MatFileError::InvalidElement("invalid dimensions".to_string()))?
+            .into_iter()
+            .map(|dim| dim as usize)
+            .collect();
+        let (_, name) = next("name")?;
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        let class = MatClass::from_id(flags & 0xff);
+        let is_2d = dims.len() >= 2 && dims[2..].iter().all(|&dim| dim == 1);
+        let (Some(class), true, false) = (class, is_2d, flags & FLAG_COMPLEX != 0) else {
+            self.unsupported.push(name);
+            return Ok(());
+        };
+
+        let (mi_type, data) = next("data")?;
+        let size = mi_size(mi_type)
+            .ok_or_else(|| MatFileError::InvalidElement(format!("`{name}` has invalid data")))?;
+        let len = dims[0]
+            .checked_mul(dims[1])
+            .and_then(|len| len.checked_mul(size));
+        if len != Some(data.len()) {
+            return Err(MatFileError::InvalidElement(format!(
+                "`{name}` has the wrong number of elements"
+            )));
+        }
+
+        let mut data = data.to_vec();
+        if big_endian {
+            for elem in data.chunks_exact_mut(size) {
+                elem.reverse();
+            }
+        }
+        self.variables.push(Variable {
+            name,
+            class,
+            rows: dims[0],
+            cols: dims[1],
+            mi_type,
+            data,
+        });
+        Ok(())
+    }
+
+    /// Writes a little-endian `.mat` file, optionally compressing each variable.
+    ///
+    /// # Errors
+    /// * If writing or compressing fails.
+    /// * `io::ErrorKind::InvalidInput` if a variable has more than `i32::MAX` rows or columns,
+    ///   or is too big for the format's 32-bit lengths.
+    pub fn write<W: Write>(&self, mut writer: W, compress: bool) -> io::Result<()> {
+        let header = b"MATLAB 5.0 MAT-file, written by qmat";
+        let mut text = [b' '; 116];
+        text[..header.len()].copy_from_slice(header);
+        writer.write_all(&text)?;
+        writer.write_all(&[0; 8])?;
+        writer.write_all(&0x0100u16.to_le_bytes())?;
+        writer.write_all(b"IM")?;
+
+        for var in &self.variables {
+            let dim = |dim: usize| {
+                i32::try_from(dim).map_err(|_| invalid_input("too many rows or columns"))
+            };
+            let mut matrix = Vec::new();
+            write_element(
+                &mut matrix,
+                MI_UINT32,
+                &[var.class.id(), 0].map(u32::to_le_bytes).concat(),
+            )?;
+            write_element(
+                &mut matrix,
+                MI_INT32,
+                &[dim(var.rows)?.to_le_bytes(), dim(var.cols)?.to_le_bytes()].concat(),
+            )?;
+            write_element(&mut matrix, MI_INT8, var.name.as_bytes())?;
+            write_element(&mut matrix, var.mi_type, &var.data)?;
+
+            let mut element = Vec::new();
+            write_element(&mut element, MI_MATRIX, &matrix)?;
+            if compress {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(&element)?;
+                let compressed = encoder.finish()?;
+                write_tag(&mut writer, MI_COMPRESSED, compressed.len())?;
+                writer.write_all(&compressed)?;
+            } else {
+                writer.write_all(&element)?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+fn invalid_input(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn write_tag<W: Write>(writer: &mut W, mi_type: u32, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len).map_err(|_| invalid_input("variable is too large"))?;
+    writer.write_all(&mi_type.to_le_bytes())?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+/// Appends a data element, padded to 8 bytes.
+fn write_element(out: &mut Vec<u8>, mi_type: u32, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len()).map_err(|_| invalid_input("variable is too large"))?;
+    out.extend_from_slice(&mi_type.to_le_bytes());
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(data);
+    out.resize(out.len() + padding(data.len()), 0);
+    Ok(())
+}
+
+fn padding(len: usize) -> usize {
+    (8 - len % 8) % 8
+}
+
+/// Iterates over the data elements in some bytes.
+struct Elements<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Elements<'a> {
+    fn read_u32(&self, bytes: &[u8]) -> u32 {
+        u32::from_mat_bytes(bytes, self.big_endian)
+    }
+
+    /// Returns the type and data of the next element.
+    fn next_element(&mut self) -> Result<Option<(u32, &'a [u8])>, MatFileError> {
+        if self.bytes.is_empty() {
+            return Ok(None);
+        }
+        let truncated = || MatFileError::InvalidElement("data element is truncated".to_string());
+        if self.bytes.len() < 8 {
+            return Err(truncated());
+        }
+
+        let first = self.read_u32(&self.bytes[..4]);
+        if first >> 16 != 0 {
+            // small data element, with the type and size packed into the first 4 bytes
+            let size = (first >> 16) as usize;
+            if size > 4 {
+                return Err(truncated());
+            }
+            let data = &self.bytes[4..4 + size];
+            self.bytes = &self.bytes[8..];
+            return Ok(Some((first & 0xffff, data)));
+        }
+
+        let size = self.read_u32(&self.bytes[4..8]) as usize;
+        let rest = &self.bytes[8..];
+        if rest.len() < size {
+            return Err(truncated());
+        }
+        let data = &rest[..size];
+        // compressed elements aren't padded
+        let end = if first == MI_COMPRESSED {
+            size
+        } else {
+            (size + padding(size)).min(rest.len())
+        };
+        self.bytes = &rest[end..];
+        Ok(Some((first, data)))
+    }
+}
+
+/// The size of an element of the given data type.
+fn mi_size(mi_type: u32) -> Option<usize> {
+    match mi_type {
+        MI_INT8 | MI_UINT8 => Some(1),
+        MI_INT16 | MI_UINT16 => Some(2),
+        MI_INT32 | MI_UINT32 | MI_SINGLE => Some(4),
+        MI_DOUBLE | MI_INT64 | MI_UINT64 => Some(8),
+        _ => None,
+    }
+}
+
+/// Decodes elements stored as `mi_type`, converting them to `T`.
+fn decode<T: MatElement>(mi_type: u32, bytes: &[u8], big_endian: bool) -> Option<Vec<T>> {
+    fn cast<S: MatElement, T: MatElement>(bytes: &[u8], big_endian: bool) -> Option<Vec<T>> {
+        if !bytes.len().is_multiple_of(S::SIZE) {
+            return None;
+        }
+        bytes
+            .chunks_exact(S::SIZE)
+            .map(|elem| T::from(S::from_mat_bytes(elem, big_endian)))
+            .collect()
+    }
+
+    match mi_type {
+        MI_INT8 => cast::<i8, T>(bytes, big_endian),
+        MI_UINT8 => cast::<u8, T>(bytes, big_endian),
+        MI_INT16 => cast::<i16, T>(bytes, big_endian),
+        MI_UINT16 => cast::<u16, T>(bytes, big_endian),
+        MI_INT32 => cast::<i32, T>(bytes, big_endian),
+        MI_UINT32 => cast::<u32, T>(bytes, big_endian),
+        MI_SINGLE => cast::<f32, T>(bytes, big_endian),
+        MI_DOUBLE => cast::<f64, T>(bytes, big_endian),
+        MI_INT64 => cast::<i64, T>(bytes, big_endian),
+        MI_UINT64 => cast::<u64, T>(bytes, big_endian),
+        _ => None,
+    }
+}
@@ -2,6 +2,65 @@
 
 use std::{iter::Sum, ops::Mul};
 
+use num_traits::Float;
+
 pub fn arr_dot<T: Mul + Sum<<T as Mul>::Output> + Copy, const M: usize>(a: [T; M], b: [T; M]) -> T {
     a.iter().enumerate().map(|(i, x)| *x * b[i]).sum()
 }
+
+/// Below this many terms, pairwise summation just adds them in order.
+const PAIRWISE_BLOCK: usize = 8;
+
+/// Sums `xs` with Kahan-Babuška (Neumaier) compensation.
+pub fn kahan_babuska_sum<T: Float>(xs: impl IntoIterator<Item = T>) -> T {
+    let (mut sum, mut comp) = (T::zero(), T::zero());
+    for x in xs {
+        let (s, err) = two_sum(sum, x);
+        sum = s;
+        comp = comp + err;
+    }
+    sum + comp
+}
+
+/// Sums `xs` by recursively splitting it in half.
+pub fn pairwise_sum<T: Float>(xs: &[T]) -> T {
+    if xs.len() <= PAIRWISE_BLOCK {
+        xs.iter().fold(T::zero(), |acc, &x| acc + x)
+    } else {
+        let (lo, hi) = xs.split_at(xs.len() / 2);
+        pairwise_sum(lo) + pairwise_sum(hi)
+    }
+}
+
+/// Same as `pairwise_sum`, but of the products `a[i] * b[i]`.
+pub fn pairwise_dot<T: Float>(a: &[T], b: &[T]) -> T {
+    if a.len() <= PAIRWISE_BLOCK {
+        a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+    } else {
+        let mid = a.len() / 2;
+        pairwise_dot(&a[..mid], &b[..mid]) + pairwise_dot(&a[mid..], &b[mid..])
+    }
+}
+
+/// The dot product from Ogita, Rump and Oishi's "Accurate Sum and Dot Product" (`Dot2`),
+/// which is as accurate as computing it in twice the precision and rounding once.
+/// Each product's rounding error is recovered exactly with a fused multiply-add.
+pub fn compensated_dot<T: Float>(a: &[T], b: &[T]) -> T {
+    let (mut sum, mut comp) = (T::zero(), T::zero());
+    for (&x, &y) in a.iter().zip(b) {
+        let prod = x * y;
+        let prod_err = x.mul_add(y, -prod);
+        let (s, sum_err) = two_sum(sum, prod);
+        sum = s;
+        comp = comp + (sum_err + prod_err);
+    }
+    sum + comp
+}
+
+/// Returns `a + b` and its rounding error, regardless of which is bigger.
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let err = (a - (sum - b_virtual)) + (b - b_virtual);
+    (sum, err)
+}
@@ -0,0 +1,312 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Integers modulo a prime, i.e. the finite field [GF(p)](https://en.wikipedia.org/wiki/Finite_field#Prime_fields).
+//!
+//! [`Gf`] is a [`Field`](crate::identities::Field) with a [`Magnitude`], so `det`, `inverse` and
+//! `solve` are exact over it, and `rank` is provided for its matrices too.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{modular::Gf, prelude::*};
+//!
+//! // a Hill cipher key over the 29 letters of some alphabet
+//! let key = matrix![3, 3; 2, 5].map_gf::<29>();
+//! let inv = key.inverse().unwrap();
+//! let plain = col_vector![7, 4].map_gf::<29>();
+//! let cipher = key.multiply::<1, 2, 2>(&plain);
+//! assert_eq!(inv.multiply::<1, 2, 2>(&cipher), plain);
+//! ```
+
+use std::{
+    fmt::{self, Display, Formatter},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use num_traits::{Inv, One, Zero};
+
+use crate::{identities::Magnitude, mat::Matrix};
+
+/// An integer modulo the prime `P`, always stored in `0..P`.
+///
+/// Using a `P` that isn't prime is a compile-time error as soon as a value is created:
+/// ```compile_fail
+/// let x = qmat::modular::Gf::<15>::new(4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    const ASSERT_PRIME: () = assert!(is_prime(P), "the modulus P must be prime");
+
+    /// Creates a new value, reducing `value` modulo `P`.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_PRIME;
+        Self(value % P)
+    }
+
+    /// Same as `new`, but takes a signed value, so that e.g. `-1` becomes `P - 1`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn from_i64(value: i64) -> Self {
+        // P fits in i128, so the remainder fits in u64
+        Self::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+
+    /// Returns the value in `0..P`.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Raises the value to the power of `exp` by repeated squaring.
+    #[must_use]
+    pub const fn pow(self, exp: u64) -> Self {
+        Self(pow_mod(self.0, exp, P))
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::modular::Gf;
+    /// assert_eq!(Gf::<7>::new(3).checked_inv(), Some(Gf::new(5)));
+    /// assert_eq!(Gf::<7>::new(0).checked_inv(), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_inv(self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            // Fermat's little theorem
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> Default for Gf<P> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(add_mod(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Div for Gf<P> {
+    type Output = Self;
+
+    /// # Panics
+    /// * If `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        Self(mul_mod(self.0, rhs.inv().0, P))
+    }
+}
+
+impl<const P: u64> Inv for Gf<P> {
+    type Output = Self;
+
+    /// # Panics
+    /// * If `self` is zero.
+    fn inv(self) -> Self {
+        self.checked_inv()
+            .expect("zero has no inverse in a finite field")
+    }
+}
+
+impl<const P: u64> AddAssign for Gf<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Gf<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Gf<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> Zero for Gf<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Gf<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Magnitude for Gf<P> {
+    type Output = bool;
+
+    /// There's no rounding error to avoid, so every nonzero value is as good a pivot as any other.
+    fn magnitude(&self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl<const P: u64> Sum for Gf<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), Add::add)
+    }
+}
+
+impl<const P: u64> Product for Gf<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl<const M: usize, const N: usize, const LEN: usize> Matrix<u64, M, N, LEN> {
+    /// Reduces every element modulo `P`.
+    ///
+    /// # Panics
+    /// * If `LEN` isn't `M * N`.
+    #[must_use]
+    pub fn map_gf<const P: u64>(&self) -> Matrix<Gf<P>, M, N, LEN> {
+        Matrix::new(self.as_flat_array().map(Gf::new)).expect("LEN must be M * N")
+    }
+}
+
+impl<const P: u64, const M: usize, const N: usize, const LEN: usize> Matrix<Gf<P>, M, N, LEN> {
+    /// Returns the [rank](https://en.wikipedia.org/wiki/Rank_(linear_algebra)) of the matrix over GF(`P`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// // the rows add up to zero mod 2
+    /// let mat = matrix![1, 1, 0; 0, 1, 1; 1, 0, 1].map_gf::<2>();
+    /// assert_eq!(mat.rank(), 2);
+    /// ```
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        let mut mat = *self;
+        mat.rref_in_place()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut out = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            out = mul_mod(out, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    out
+}
+
+/// Deterministic Miller-Rabin, which is exact for every `u64` with these bases.
+const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut base = 0;
+    while base < BASES.len() {
+        if n.is_multiple_of(BASES[base]) {
+            return n == BASES[base];
+        }
+        base += 1;
+    }
+
+    // n - 1 = odd * 2^twos
+    let mut odd = n - 1;
+    let mut twos = 0;
+    while odd.is_multiple_of(2) {
+        odd /= 2;
+        twos += 1;
+    }
+
+    let mut base = 0;
+    while base < BASES.len() {
+        let mut witness = pow_mod(BASES[base], odd, n);
+        base += 1;
+        if witness == 1 || witness == n - 1 {
+            continue;
+        }
+        let mut round = 1;
+        while round < twos {
+            witness = mul_mod(witness, witness, n);
+            if witness == n - 1 {
+                break;
+            }
+            round += 1;
+        }
+        if round == twos {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,541 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Reading and writing the [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) exchange format.
+//!
+//! Both the `coordinate` and `array` formats can be read, with `real`, `integer`, `complex` or `pattern`
+//! fields and `general`, `symmetric`, `skew-symmetric` or `hermitian` symmetries.
+//! Dense matrices are written in the `array` format and [`CooMatrix`] in the `coordinate` format,
+//! both as `general`.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::prelude::*;
+//!
+//! let file = "%%MatrixMarket matrix coordinate real symmetric
+//! % a comment
+//! 3 3 2
+//! 1 1 4.0
+//! 3 2 -1.5
+//! ";
+//! let mat = Matrix::<f64, 3, 3, 9>::from_mtx(file.as_bytes()).unwrap();
+//! assert_eq!(mat, matrix![4.0, 0.0, 0.0; 0.0, 0.0, -1.5; 0.0, -1.5, 0.0]);
+//! ```
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+use num_complex::Complex;
+use num_traits::{One, Zero};
+
+use crate::{dmat::DMatrix, errors::MtxError, mat::Matrix, position::Position, sparse::CooMatrix};
+
+/// The type of the values stored in a Matrix Market file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxField {
+    Real,
+    Integer,
+    Complex,
+    /// Only the positions of the entries are stored. They're read as 1.
+    Pattern,
+}
+
+impl fmt::Display for MtxField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Real => "real",
+            Self::Integer => "integer",
+            Self::Complex => "complex",
+            Self::Pattern => "pattern",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MtxFormat {
+    Coordinate,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MtxSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+/// Element types that can be read from and written to Matrix Market files.
+pub trait MtxScalar: Copy + Zero {
+    /// The field written in the header.
+    const FIELD: MtxField;
+
+    /// Whether values of the given field can be stored in this type.
+    fn supports(field: MtxField) -> bool;
+
+    /// Parses the value of an entry, split on whitespace (empty for `pattern`).
+    fn parse_mtx(field: MtxField, tokens: &[&str]) -> Option<Self>;
+
+    /// Formats the value of an entry.
+    fn fmt_mtx(&self) -> String;
+
+    /// Returns the negated value, for the mirrored entry of a skew-symmetric matrix.
+    fn neg_mtx(self) -> Option<Self>;
+
+    /// Returns the conjugated value, for the mirrored entry of a hermitian matrix.
+    #[must_use]
+    fn conj_mtx(self) -> Self {
+        self
+    }
+}
+
+macro_rules! int_mtx {
+    ($T:ty) => {
+        impl MtxScalar for $T {
+            const FIELD: MtxField = MtxField::Integer;
+
+            fn supports(field: MtxField) -> bool {
+                matches!(field, MtxField::Integer | MtxField::Pattern)
+            }
+
+            fn parse_mtx(field: MtxField, tokens: &[&str]) -> Option<Self> {
+                match (field, tokens) {
+                    (MtxField::Integer, [val]) => val.parse().ok(),
+                    (MtxField::Pattern, []) => Some(Self::one()),
+                    _ => None,
+                }
+            }
+
+            fn fmt_mtx(&self) -> String {
+                self.to_string()
+            }
+
+            fn neg_mtx(self) -> Option<Self> {
+                self.checked_neg()
+            }
+        }
+    };
+}
+
+int_mtx!(i8);
+int_mtx!(i16);
+int_mtx!(i32);
+int_mtx!(i64);
+int_mtx!(i128);
+int_mtx!(isize);
+
+int_mtx!(u8);
+int_mtx!(u16);
+int_mtx!(u32);
+int_mtx!(u64);
+int_mtx!(u128);
+int_mtx!(usize);
+
+macro_rules! float_mtx {
+    ($T:ty) => {
+        impl MtxScalar for $T {
+            const FIELD: MtxField = MtxField::Real;
+
+            fn supports(field: MtxField) -> bool {
+                field != MtxField::Complex
+            }
+
+            fn parse_mtx(field: MtxField, tokens: &[&str]) -> Option<Self> {
+                match (field, tokens) {
+                    (MtxField::Real | MtxField::Integer, [val]) => val.parse().ok(),
+                    (MtxField::Pattern, []) => Some(Self::one()),
+                    _ => None,
+                }
+            }
+
+            fn fmt_mtx(&self) -> String {
+                format!("{self:?}")
+            }
+
+            fn neg_mtx(self) -> Option<Self> {
+                Some(-self)
+            }
+        }
+
+        impl MtxScalar for Complex<$T> {
+            const FIELD: MtxField = MtxField::Complex;
+
+            fn supports(_: MtxField) -> bool {
+                true
+            }
+
+            fn parse_mtx(field: MtxField, tokens: &[&str]) -> Option<Self> {
+                match (field, tokens) {
+                    (MtxField::Complex, [re, im]) => {
+                        Some(Complex::new(re.parse().ok()?, im.parse().ok()?))
+                    }
+                    (MtxField::Real | MtxField::Integer, [re]) => {
+                        Some(Complex::new(re.parse().ok()?, 0.0))
+                    }
+                    (MtxField::Pattern, []) => Some(Self::one()),
+                    _ => None,
+                }
+            }
+
+            fn fmt_mtx(&self) -> String {
+                format!("{:?} {:?}", self.re, self.im)
+            }
+
+            fn neg_mtx(self) -> Option<Self> {
+                Some(-self)
+            }
+
+            fn conj_mtx(self) -> Self {
+                self.conj()
+            }
+        }
+    };
+}
+
+float_mtx!(f32);
+float_mtx!(f64);
+
+/// The contents of a file, with the symmetric entries already mirrored.
+struct Parsed<T> {
+    format: MtxFormat,
+    rows: usize,
+    cols: usize,
+    entries: Vec<(Position, T)>,
+    /// The size line and its number, for reporting dimensions too big for a dense matrix.
+    size: (usize, String),
+}
+
+impl<T: MtxScalar> Parsed<T> {
+    /// Adds the entries into zeroed row-major data, summing duplicates like `CooMatrix::to_dense`.
+    fn add_into(self, data: &mut [T]) {
+        for (pos, val) in self.entries {
+            let i = pos.0 * self.cols + pos.1;
+            data[i] = data[i] + val;
+        }
+    }
+}
+
+fn invalid_header(line: usize, text: &str) -> MtxError {
+    MtxError::InvalidHeader {
+        line,
+        text: text.to_string(),
+    }
+}
+
+fn parse_banner(text: &str) -> Result<(MtxFormat, MtxField, MtxSymmetry), MtxError> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let [banner, "matrix", format, field, symmetry] = words[..] else {
+        return Err(invalid_header(1, text));
+    };
+    if banner != "%%matrixmarket" {
+        return Err(invalid_header(1, text));
+    }
+
+    let format = match format {
+        "coordinate" => MtxFormat::Coordinate,
+        "array" => MtxFormat::Array,
+        _ => return Err(invalid_header(1, text)),
+    };
+    let field = match field {
+        "real" | "double" => MtxField::Real,
+        "integer" => MtxField::Integer,
+        "complex" => MtxField::Complex,
+        "pattern" if format == MtxFormat::Coordinate => MtxField::Pattern,
+        _ => return Err(invalid_header(1, text)),
+    };
+    let symmetry = match symmetry {
+        "general" => MtxSymmetry::General,
+        "symmetric" => MtxSymmetry::Symmetric,
+        "skew-symmetric" => MtxSymmetry::SkewSymmetric,
+        "hermitian" if field == MtxField::Complex => MtxSymmetry::Hermitian,
+        _ => return Err(invalid_header(1, text)),
+    };
+
+    Ok((format, field, symmetry))
+}
+
+fn read<T: MtxScalar, R: BufRead>(reader: R) -> Result<Parsed<T>, MtxError> {
+    let mut lines = reader.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    let banner = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(invalid_header(1, "")),
+    };
+    let (format, field, symmetry) = parse_banner(&banner)?;
+    if !T::supports(field) {
+        return Err(MtxError::IncompatibleField(field));
+    }
+
+    // skip comments and blank lines
+    let mut lines = lines.filter(|(_, line)| {
+        line.as_ref().map_or(true, |line| {
+            !line.starts_with('%') && !line.trim().is_empty()
+        })
+    });
+
+    let (size_line, size_text) = match lines.next() {
+        Some((i, line)) => (i, line?),
+        None => return Err(invalid_header(2, "")),
+    };
+    let size: Vec<usize> = size_text
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid_header(size_line, &size_text))?;
+    let (rows, cols, count) = match (format, &size[..]) {
+        (MtxFormat::Coordinate, &[rows, cols, nnz]) => (rows, cols, nnz),
+        (MtxFormat::Array, &[rows, cols]) => match array_count(rows, cols, symmetry) {
+            Some(count) => (rows, cols, count),
+            None => return Err(invalid_header(size_line, &size_text)),
+        },
+        _ => return Err(invalid_header(size_line, &size_text)),
+    };
+    if symmetry != MtxSymmetry::General && rows != cols {
+        return Err(invalid_header(size_line, &size_text));
+    }
+
+    let mut array_positions = ArrayPositions {
+        rows,
+        cols,
+        symmetry,
+        next: Position(0, 0),
+    };
+    // the counts come from the file, so they can't be trusted with an allocation up front
+    let mut entries = Vec::new();
+    let mut found = 0;
+
+    for (i, line) in lines {
+        let line = line?;
+        found += 1;
+        if found > count {
+            continue;
+        }
+
+        let invalid_entry = || MtxError::InvalidEntry {
+            line: i,
+            text: line.clone(),
+        };
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let (pos, tokens) = match format {
+            MtxFormat::Coordinate => {
+                let [row, col, tokens @ ..] = &tokens[..] else {
+                    return Err(invalid_entry());
+                };
+                let row: usize = row.parse().map_err(|_| invalid_entry())?;
+                let col: usize = col.parse().map_err(|_| invalid_entry())?;
+                if row == 0 || col == 0 || row > rows || col > cols {
+                    return Err(MtxError::EntryOutOfBounds {
+                        line: i,
+                        pos: Position(row.wrapping_sub(1), col.wrapping_sub(1)),
+                    });
+                }
+                (Position(row - 1, col - 1), tokens)
+            }
+            MtxFormat::Array => (array_positions.advance(), &tokens[..]),
+        };
+
+        let val = T::parse_mtx(field, tokens).ok_or_else(invalid_entry)?;
+        entries.push((pos, val));
+
+        if pos.0 != pos.1 {
+            let mirrored = match symmetry {
+                MtxSymmetry::General => continue,
+                MtxSymmetry::Symmetric => val,
+                MtxSymmetry::SkewSymmetric => val.neg_mtx().ok_or_else(invalid_entry)?,
+                MtxSymmetry::Hermitian => val.conj_mtx(),
+            };
+            entries.push((Position(pos.1, pos.0), mirrored));
+        }
+    }
+
+    if found != count {
+        return Err(MtxError::WrongEntryCount {
+            expected: count,
+            found,
+        });
+    }
+
+    Ok(Parsed {
+        format,
+        rows,
+        cols,
+        entries,
+        size: (size_line, size_text),
+    })
+}
+
+/// The number of values stored in the array format, or `None` if it overflows.
+fn array_count(rows: usize, cols: usize, symmetry: MtxSymmetry) -> Option<usize> {
+    match symmetry {
+        MtxSymmetry::General => rows.checked_mul(cols),
+        MtxSymmetry::Symmetric | MtxSymmetry::Hermitian => {
+            Some(rows.checked_mul(rows.checked_add(1)?)? / 2)
+        }
+        MtxSymmetry::SkewSymmetric => Some(rows.checked_mul(rows.saturating_sub(1))? / 2),
+    }
+}
+
+/// The array format is column-major, and only stores the lower triangle for symmetric matrices.
+struct ArrayPositions {
+    rows: usize,
+    cols: usize,
+    symmetry: MtxSymmetry,
+    next: Position,
+}
+
+impl ArrayPositions {
+    fn first_row(&self, col: usize) -> usize {
+        match self.symmetry {
+            MtxSymmetry::General => 0,
+            MtxSymmetry::Symmetric | MtxSymmetry::Hermitian => col,
+            MtxSymmetry::SkewSymmetric => col + 1,
+        }
+    }
+
+    fn advance(&mut self) -> Position {
+        if self.next == Position(0, 0) {
+            self.next.0 = self.first_row(0);
+        }
+        let pos = self.next;
+
+        self.next.0 += 1;
+        if self.next.0 >= self.rows && self.next.1 + 1 < self.cols {
+            self.next.1 += 1;
+            self.next.0 = self.first_row(self.next.1);
+        }
+
+        pos
+    }
+}
+
+fn write_dense<T: MtxScalar, W: Write>(
+    mut writer: W,
+    data: &[T],
+    rows: usize,
+    cols: usize,
+) -> io::Result<()> {
+    writeln!(writer, "%%MatrixMarket matrix array {} general", T::FIELD)?;
+    writeln!(writer, "{rows} {cols}")?;
+    for col in 0..cols {
+        for row in 0..rows {
+            writeln!(writer, "{}", data[row * cols + col].fmt_mtx())?;
+        }
+    }
+    Ok(())
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: MtxScalar,
+{
+    /// Reads a matrix from a Matrix Market file, in either format.
+    ///
+    /// # Errors
+    /// * `MtxError::DimensionMismatch` if the file's dimensions aren't (`M`, `N`).
+    /// * Same as `DMatrix::from_mtx`.
+    ///
+    /// # Panics
+    /// * If `M * N != LEN`.
+    pub fn from_mtx<R: BufRead>(reader: R) -> Result<Self, MtxError> {
+        let parsed = read(reader)?;
+        if (parsed.rows, parsed.cols) != (M, N) {
+            return Err(MtxError::DimensionMismatch {
+                expected: (M, N),
+                found: (parsed.rows, parsed.cols),
+            });
+        }
+
+        let mut data = [T::zero(); LEN];
+        parsed.add_into(&mut data);
+        Ok(Self::new(data).unwrap())
+    }
+
+    /// Writes the matrix in the Matrix Market `array` format.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_mtx<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_dense(writer, self.as_flat_array(), M, N)
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: MtxScalar,
+{
+    /// Reads a matrix from a Matrix Market file, in either format.
+    /// Elements without an entry are zero, and repeated entries are summed.
+    ///
+    /// # Errors
+    /// * `MtxError::Io` if reading fails.
+    /// * `MtxError::InvalidHeader` if the banner or size line is malformed,
+    ///   or if the dimensions are too big to allocate a dense matrix for.
+    /// * `MtxError::IncompatibleField` if the values can't be stored in `T`.
+    /// * `MtxError::InvalidEntry` if an entry can't be parsed.
+    /// * `MtxError::EntryOutOfBounds` if an entry is outside of the matrix.
+    /// * `MtxError::WrongEntryCount` if the number of entries doesn't match the size line.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    pub fn from_mtx<R: BufRead>(reader: R) -> Result<Self, MtxError> {
+        let parsed = read(reader)?;
+        let too_big = || invalid_header(parsed.size.0, &parsed.size.1);
+        let len = parsed.rows.checked_mul(parsed.cols).ok_or_else(too_big)?;
+        let mut data = Vec::new();
+        data.try_reserve_exact(len).map_err(|_| too_big())?;
+        data.resize(len, T::zero());
+
+        let (rows, cols) = (parsed.rows, parsed.cols);
+        parsed.add_into(&mut data);
+        Ok(DMatrix::new(rows, cols, data).unwrap())
+    }
+
+    /// Writes the matrix in the Matrix Market `array` format.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_mtx<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_dense(writer, self.as_flat_slice(), self.rows(), self.cols())
+    }
+}
+
+impl<T> CooMatrix<T>
+where
+    T: MtxScalar,
+{
+    /// Reads a sparse matrix from a Matrix Market file, in either format.
+    /// Zeros stored in the `array` format are skipped.
+    ///
+    /// # Errors
+    /// * Same as `DMatrix::from_mtx`.
+    pub fn from_mtx<R: BufRead>(reader: R) -> Result<Self, MtxError> {
+        let parsed = read::<T, R>(reader)?;
+        let mut sparse = CooMatrix::new(parsed.rows, parsed.cols);
+        for (pos, val) in parsed.entries {
+            if parsed.format == MtxFormat::Coordinate || !val.is_zero() {
+                sparse.push(pos, val);
+            }
+        }
+        Ok(sparse)
+    }
+
+    /// Writes the matrix in the Matrix Market `coordinate` format.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_mtx<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix coordinate {} general",
+            T::FIELD
+        )?;
+        writeln!(writer, "{} {} {}", self.rows(), self.cols(), self.nnz())?;
+        for (pos, val) in self.entries() {
+            writeln!(writer, "{} {} {}", pos.0 + 1, pos.1 + 1, val.fmt_mtx())?;
+        }
+        Ok(())
+    }
+}
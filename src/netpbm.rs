@@ -0,0 +1,511 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Reading and writing Netpbm PGM (grayscale) and PPM (RGB) images.
+//!
+//! Grayscale images are matrices of `u8` or `u16` samples, and RGB images are matrices of `[u8; 3]`
+//! or `[u16; 3]`, with the image's rows as the matrix's rows.
+//! Both the ASCII (`P2`/`P3`) and binary (`P5`/`P6`) encodings can be read and written.
+//! Samples are scaled from the file's maxval to the sample type's whole range when read,
+//! so a 10-bit image with a maxval of 1023 reads into `u16`s from 0 to 65535;
+//! `quantize` and `dequantize` convert between float matrices and samples.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{netpbm::NetpbmEncoding, prelude::*};
+//!
+//! let heights = matrix![0.0, 0.5; 0.75, 1.0];
+//! let gray: Matrix<u8, 2, 2, 4> = heights.quantize(None);
+//! assert_eq!(gray, matrix![0, 128; 191, 255]);
+//!
+//! let mut buf = Vec::new();
+//! gray.to_pgm(&mut buf, NetpbmEncoding::Ascii).unwrap();
+//! assert_eq!(String::from_utf8(buf.clone()).unwrap(), "P2\n2 2\n255\n0 128\n191 255\n");
+//! assert_eq!(Matrix::<u8, 2, 2, 4>::from_pgm(buf.as_slice()).unwrap(), gray);
+//! ```
+
+use std::io::{self, Read, Write};
+
+use num_traits::Float;
+
+use crate::{dmat::DMatrix, errors::NetpbmError, mat::Matrix};
+
+/// How the samples of an image are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetpbmEncoding {
+    /// Decimal numbers separated by whitespace (`P2`/`P3`).
+    Ascii,
+    /// Raw bytes, big-endian if the maxval is over 255 (`P5`/`P6`).
+    Binary,
+}
+
+/// A sample type for Netpbm images.
+pub trait NetpbmSample: Copy {
+    /// The largest sample, written as the maxval.
+    const MAX: u16;
+
+    /// Converts from a sample that's at most `MAX`.
+    fn from_sample(sample: u16) -> Self;
+
+    /// Converts to a sample.
+    fn to_sample(self) -> u16;
+}
+
+impl NetpbmSample for u8 {
+    const MAX: u16 = 255;
+
+    fn from_sample(sample: u16) -> Self {
+        u8::try_from(sample).unwrap_or(u8::MAX)
+    }
+
+    fn to_sample(self) -> u16 {
+        self.into()
+    }
+}
+
+impl NetpbmSample for u16 {
+    const MAX: u16 = u16::MAX;
+
+    fn from_sample(sample: u16) -> Self {
+        sample
+    }
+
+    fn to_sample(self) -> u16 {
+        self
+    }
+}
+
+/// A pixel of a Netpbm image: a sample for PGM, or an RGB triple of them for PPM.
+pub trait NetpbmPixel: Copy {
+    type Sample: NetpbmSample;
+
+    /// The number of samples per pixel.
+    const CHANNELS: usize;
+
+    /// The magic numbers of the ASCII and binary encodings.
+    const MAGIC: (&'static str, &'static str);
+
+    /// Creates a pixel from exactly `CHANNELS` samples.
+    fn from_samples(samples: &[u16]) -> Self;
+
+    /// Appends the pixel's samples.
+    fn write_samples(&self, out: &mut Vec<u16>);
+}
+
+impl<S: NetpbmSample> NetpbmPixel for S {
+    type Sample = S;
+    const CHANNELS: usize = 1;
+    const MAGIC: (&'static str, &'static str) = ("P2", "P5");
+
+    fn from_samples(samples: &[u16]) -> Self {
+        S::from_sample(samples[0])
+    }
+
+    fn write_samples(&self, out: &mut Vec<u16>) {
+        out.push(self.to_sample());
+    }
+}
+
+impl<S: NetpbmSample> NetpbmPixel for [S; 3] {
+    type Sample = S;
+    const CHANNELS: usize = 3;
+    const MAGIC: (&'static str, &'static str) = ("P3", "P6");
+
+    fn from_samples(samples: &[u16]) -> Self {
+        [0, 1, 2].map(|i| S::from_sample(samples[i]))
+    }
+
+    fn write_samples(&self, out: &mut Vec<u16>) {
+        out.extend(self.iter().map(|sample| sample.to_sample()));
+    }
+}
+
+impl<S, const M: usize, const N: usize, const LEN: usize> Matrix<S, M, N, LEN>
+where
+    S: NetpbmSample,
+{
+    /// Writes the matrix as a PGM image, with the type's largest value as the maxval.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_pgm<W: Write>(&self, writer: W, encoding: NetpbmEncoding) -> io::Result<()> {
+        write_netpbm(writer, self.as_flat_array(), M, N, encoding)
+    }
+
+    /// Reads a PGM image of `N` by `M` pixels, in either encoding.
+    ///
+    /// # Errors
+    /// * `NetpbmError::DimensionMismatch` if the image isn't `N` pixels wide and `M` tall.
+    /// * Same as `DMatrix::from_pgm`.
+    pub fn from_pgm<R: Read>(reader: R) -> Result<Self, NetpbmError> {
+        read_matrix(reader)
+    }
+
+    /// Converts the samples to floats from 0 to 1.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    #[must_use]
+    pub fn dequantize<F: Float>(&self) -> Matrix<F, M, N, LEN> {
+        let data = self.as_flat_array();
+        Matrix::new(std::array::from_fn(|i| dequantize(data[i]))).unwrap()
+    }
+}
+
+impl<S, const M: usize, const N: usize, const LEN: usize> Matrix<[S; 3], M, N, LEN>
+where
+    S: NetpbmSample,
+{
+    /// Writes the matrix as a PPM image, with the type's largest value as the maxval.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_ppm<W: Write>(&self, writer: W, encoding: NetpbmEncoding) -> io::Result<()> {
+        write_netpbm(writer, self.as_flat_array(), M, N, encoding)
+    }
+
+    /// Reads a PPM image of `N` by `M` pixels, in either encoding.
+    ///
+    /// # Errors
+    /// * `NetpbmError::DimensionMismatch` if the image isn't `N` pixels wide and `M` tall.
+    /// * Same as `DMatrix::from_pgm`.
+    pub fn from_ppm<R: Read>(reader: R) -> Result<Self, NetpbmError> {
+        read_matrix(reader)
+    }
+}
+
+impl<F, const M: usize, const N: usize, const LEN: usize> Matrix<F, M, N, LEN>
+where
+    F: Float,
+{
+    /// Maps `range` linearly onto the samples from 0 to `S::MAX`, rounding and clamping.
+    /// If `range` is `None`, the matrix's smallest and largest elements are used.
+    /// NaNs and constant matrices become 0.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    #[must_use]
+    pub fn quantize<S: NetpbmSample>(&self, range: Option<(F, F)>) -> Matrix<S, M, N, LEN> {
+        let data = self.as_flat_array();
+        let (lo, hi) = range.unwrap_or_else(|| min_max(data));
+        Matrix::new(std::array::from_fn(|i| quantize(data[i], lo, hi))).unwrap()
+    }
+}
+
+impl<S> DMatrix<S>
+where
+    S: NetpbmSample,
+{
+    /// Same as `Matrix::to_pgm`.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_pgm<W: Write>(&self, writer: W, encoding: NetpbmEncoding) -> io::Result<()> {
+        write_netpbm(
+            writer,
+            self.as_flat_slice(),
+            self.rows(),
+            self.cols(),
+            encoding,
+        )
+    }
+
+    /// Reads a PGM image, in either encoding, taking the dimensions from the image.
+    /// Samples are scaled from the image's maxval to `S::MAX`.
+    ///
+    /// # Errors
+    /// * `NetpbmError::Io` if reading fails.
+    /// * `NetpbmError::WrongFormat` if the image isn't a PGM.
+    /// * `NetpbmError::InvalidHeader` if the width, height or maxval is missing or malformed,
+    ///   or if the image has more samples than fit in a `usize`.
+    /// * `NetpbmError::MaxvalTooLarge` if the maxval doesn't fit in `S`.
+    /// * `NetpbmError::InvalidSample` if a sample is malformed or over the maxval.
+    /// * `NetpbmError::Truncated` if there are fewer samples than the dimensions say.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    pub fn from_pgm<R: Read>(reader: R) -> Result<Self, NetpbmError> {
+        let (rows, cols, data) = read_netpbm(reader)?;
+        Ok(DMatrix::new(rows, cols, data).unwrap())
+    }
+
+    /// Same as `Matrix::dequantize`.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    #[must_use]
+    pub fn dequantize<F: Float>(&self) -> DMatrix<F> {
+        let data = self.iter().map(|&sample| dequantize(sample)).collect();
+        DMatrix::new(self.rows(), self.cols(), data).unwrap()
+    }
+}
+
+impl<S> DMatrix<[S; 3]>
+where
+    S: NetpbmSample,
+{
+    /// Same as `Matrix::to_ppm`.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_ppm<W: Write>(&self, writer: W, encoding: NetpbmEncoding) -> io::Result<()> {
+        write_netpbm(
+            writer,
+            self.as_flat_slice(),
+            self.rows(),
+            self.cols(),
+            encoding,
+        )
+    }
+
+    /// Same as `DMatrix::from_pgm`, but for PPM images.
+    ///
+    /// # Errors
+    /// * Same as `DMatrix::from_pgm`.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    pub fn from_ppm<R: Read>(reader: R) -> Result<Self, NetpbmError> {
+        let (rows, cols, data) = read_netpbm(reader)?;
+        Ok(DMatrix::new(rows, cols, data).unwrap())
+    }
+}
+
+impl<F> DMatrix<F>
+where
+    F: Float,
+{
+    /// Same as `Matrix::quantize`.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    #[must_use]
+    pub fn quantize<S: NetpbmSample>(&self, range: Option<(F, F)>) -> DMatrix<S> {
+        let (lo, hi) = range.unwrap_or_else(|| min_max(self.as_flat_slice()));
+        let data = self.iter().map(|&x| quantize(x, lo, hi)).collect();
+        DMatrix::new(self.rows(), self.cols(), data).unwrap()
+    }
+}
+
+fn min_max<F: Float>(data: &[F]) -> (F, F) {
+    data.iter()
+        .filter(|x| !x.is_nan())
+        .fold((F::infinity(), F::neg_infinity()), |(lo, hi), &x| {
+            (lo.min(x), hi.max(x))
+        })
+}
+
+fn quantize<F: Float, S: NetpbmSample>(x: F, lo: F, hi: F) -> S {
+    let max = F::from(S::MAX).unwrap_or_else(F::one);
+    let scaled = ((x - lo) / (hi - lo) * max).round();
+    let sample = if scaled.is_nan() {
+        0
+    } else {
+        scaled.max(F::zero()).min(max).to_u16().unwrap_or(0)
+    };
+    S::from_sample(sample)
+}
+
+fn dequantize<S: NetpbmSample, F: Float>(sample: S) -> F {
+    let to_float = |x: u16| F::from(x).unwrap_or_else(F::zero);
+    to_float(sample.to_sample()) / to_float(S::MAX)
+}
+
+fn read_matrix<P, R, const M: usize, const N: usize, const LEN: usize>(
+    reader: R,
+) -> Result<Matrix<P, M, N, LEN>, NetpbmError>
+where
+    P: NetpbmPixel,
+    R: Read,
+{
+    let (rows, cols, data) = read_netpbm(reader)?;
+    let mismatch = NetpbmError::DimensionMismatch {
+        expected: (M, N),
+        found: (rows, cols),
+    };
+    if rows != M || cols != N {
+        return Err(mismatch);
+    }
+    match data.try_into() {
+        Ok(data) => Matrix::new(data).map_err(|_| mismatch),
+        Err(_) => Err(mismatch),
+    }
+}
+
+fn write_netpbm<P, W>(
+    mut writer: W,
+    data: &[P],
+    rows: usize,
+    cols: usize,
+    encoding: NetpbmEncoding,
+) -> io::Result<()>
+where
+    P: NetpbmPixel,
+    W: Write,
+{
+    let maxval = P::Sample::MAX;
+    let magic = match encoding {
+        NetpbmEncoding::Ascii => P::MAGIC.0,
+        NetpbmEncoding::Binary => P::MAGIC.1,
+    };
+    writeln!(writer, "{magic}\n{cols} {rows}\n{maxval}")?;
+
+    let mut samples = Vec::with_capacity(data.len() * P::CHANNELS);
+    for pixel in data {
+        pixel.write_samples(&mut samples);
+    }
+
+    match encoding {
+        NetpbmEncoding::Ascii if cols > 0 => {
+            for row in samples.chunks(cols * P::CHANNELS) {
+                let row: Vec<String> = row.iter().map(ToString::to_string).collect();
+                writeln!(writer, "{}", row.join(" "))?;
+            }
+        }
+        NetpbmEncoding::Ascii => {}
+        NetpbmEncoding::Binary => {
+            let mut bytes = Vec::with_capacity(samples.len() * 2);
+            for sample in samples {
+                if maxval > 255 {
+                    bytes.extend_from_slice(&sample.to_be_bytes());
+                } else {
+                    bytes.push(u8::try_from(sample).unwrap_or(u8::MAX));
+                }
+            }
+            writer.write_all(&bytes)?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Returns the rows, cols and row-major pixels.
+fn read_netpbm<P, R>(mut reader: R) -> Result<(usize, usize, Vec<P>), NetpbmError>
+where
+    P: NetpbmPixel,
+    R: Read,
+{
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let mut cursor = Cursor {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    let magic = cursor.token().unwrap_or_default();
+    let encoding = if magic == P::MAGIC.0.as_bytes() {
+        NetpbmEncoding::Ascii
+    } else if magic == P::MAGIC.1.as_bytes() {
+        NetpbmEncoding::Binary
+    } else {
+        return Err(NetpbmError::WrongFormat {
+            expected: P::MAGIC,
+            found: String::from_utf8_lossy(magic).into_owned(),
+        });
+    };
+
+    let mut header_field = |name: &str| {
+        cursor
+            .number()
+            .ok_or_else(|| NetpbmError::InvalidHeader(format!("missing or invalid {name}")))
+    };
+    let cols = header_field("width")?;
+    let rows = header_field("height")?;
+    let maxval = header_field("maxval")?;
+    let maxval = u16::try_from(maxval)
+        .ok()
+        .filter(|&maxval| maxval > 0)
+        .ok_or_else(|| NetpbmError::InvalidHeader(format!("maxval {maxval} isn't 1 to 65535")))?;
+    if maxval > P::Sample::MAX {
+        return Err(NetpbmError::MaxvalTooLarge(maxval));
+    }
+
+    let count = rows
+        .checked_mul(cols)
+        .and_then(|pixels| pixels.checked_mul(P::CHANNELS))
+        .ok_or_else(|| NetpbmError::InvalidHeader(format!("{cols}x{rows} is too big")))?;
+    // every sample takes at least a byte, so a header can't ask for more than the input holds
+    if count > bytes.len() - cursor.pos {
+        return Err(NetpbmError::Truncated);
+    }
+    let mut samples = Vec::with_capacity(count);
+    match encoding {
+        NetpbmEncoding::Ascii => {
+            for index in 0..count {
+                let token = cursor.token().ok_or(NetpbmError::Truncated)?;
+                let sample = std::str::from_utf8(token)
+                    .ok()
+                    .and_then(|token| token.parse::<u16>().ok())
+                    .filter(|&sample| sample <= maxval)
+                    .ok_or(NetpbmError::InvalidSample { index })?;
+                samples.push(rescale(sample, maxval, P::Sample::MAX));
+            }
+        }
+        NetpbmEncoding::Binary => {
+            // exactly one whitespace character separates the maxval from the raster
+            let raster = bytes.get(cursor.pos + 1..).unwrap_or_default();
+            let width = if maxval > 255 { 2 } else { 1 };
+            if raster.len() / width < count {
+                return Err(NetpbmError::Truncated);
+            }
+            for (index, sample) in raster.chunks_exact(width).take(count).enumerate() {
+                let sample = match *sample {
+                    [hi, lo] => u16::from_be_bytes([hi, lo]),
+                    [sample] => sample.into(),
+                    _ => unreachable!("chunks are 1 or 2 bytes"),
+                };
+                if sample > maxval {
+                    return Err(NetpbmError::InvalidSample { index });
+                }
+                samples.push(rescale(sample, maxval, P::Sample::MAX));
+            }
+        }
+    }
+
+    let pixels = samples
+        .chunks_exact(P::CHANNELS)
+        .map(P::from_samples)
+        .collect();
+    Ok((rows, cols, pixels))
+}
+
+/// Maps a sample from `0..=from` onto `0..=to`, rounding to the nearest.
+fn rescale(sample: u16, from: u16, to: u16) -> u16 {
+    let (sample, from, to) = (u32::from(sample), u32::from(from), u32::from(to));
+    u16::try_from((sample * to + from / 2) / from).unwrap_or(u16::MAX)
+}
+
+/// Reads whitespace-separated tokens, skipping `#` comments.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn token(&mut self) -> Option<&'a [u8]> {
+        loop {
+            match self.bytes.get(self.pos)? {
+                b'#' => {
+                    while self.bytes.get(self.pos).is_some_and(|&b| b != b'\n') {
+                        self.pos += 1;
+                    }
+                }
+                b if b.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+
+        let start = self.pos;
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|&b| !b.is_ascii_whitespace() && b != b'#')
+        {
+            self.pos += 1;
+        }
+        Some(&self.bytes[start..self.pos])
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        std::str::from_utf8(self.token()?).ok()?.parse().ok()
+    }
+}
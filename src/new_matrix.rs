@@ -11,29 +11,165 @@
 /// ```
 ///
 /// ## From nested rows
+/// The number of rows and columns are counted when the macro is expanded, so the elements can be
+/// any expressions, including local variables.
 /// ```rust
+/// let (a, b) = (0, 3);
 /// let mat = qmat::matrix!([
-///     [0, 1],
-///     [2, 3]
+///     [a, 1],
+///     [2, b]
 /// ]);
 /// assert_eq!(mat[[1, 1]], 3);
 /// ```
+///
+/// Rows of different lengths are rejected at compile time.
+/// ```compile_fail
+/// let mat = qmat::matrix!([[0, 1], [2, 3, 4]]);
+/// ```
+///
+/// ## From MATLAB-style rows
+/// Rows are separated by semicolons. A single row needs a trailing semicolon (or use
+/// [`row_vector!`](crate::row_vector)), otherwise it'd be read as dimensions and flat data.
+/// ```rust
+/// let mat = qmat::matrix![1, 2, 3; 4, 5, 6];
+/// assert_eq!(mat.rows(), 2);
+/// assert_eq!(mat.cols(), 3);
+/// assert_eq!(mat[[1, 0]], 4);
+///
+/// let single_row = qmat::matrix![1, 2, 3;];
+/// assert_eq!(single_row.rows(), 1);
+/// ```
+///
+/// ```compile_fail
+/// let mat = qmat::matrix![1, 2; 3];
+/// ```
+///
+/// ## From an array of rows
+/// Any other expression is treated as a `[[T; N]; M]`, such as a local variable or a `const`
+/// item. The dimensions are taken from its type, which works for up to 16 rows and columns.
+/// ```rust
+/// let rows = [[0, 1, 2], [3, 4, 5]];
+/// let mat = qmat::matrix!(rows);
+/// assert_eq!(mat[[1, 2]], 5);
+///
+/// const ROWS: [[i32; 2]; 2] = [[0, 1], [2, 3]];
+/// let mat = qmat::matrix!(ROWS);
+/// assert_eq!(mat[[1, 0]], 2);
+/// ```
 #[macro_export]
 macro_rules! matrix {
     ($M:expr, $N:expr, $data:expr) => {
         $crate::mat::Matrix::<_, $M, $N, { $M * $N }>::new($data).unwrap()
     };
-    ($rows:tt) => {{
-        const M: usize = { $rows.len() };
-        if M == 0 {
-            panic!("no data provided");
-        }
-        const N: usize = { $rows[0].len() };
-        const LEN: usize = { M * N };
-        $crate::mat::Matrix::<_, M, N, LEN>::from_rows($rows).unwrap()
+    ([$(,)?]) => {
+        compile_error!("matrix!: at least one row is required")
+    };
+    ([$([$($x:expr),* $(,)?]),+ $(,)?]) => {
+        $crate::__matrix_from_rows!($([$($x),*])+)
+    };
+    ($($x:expr),+ ; $($($y:expr),+);* $(;)?) => {
+        $crate::__matrix_from_rows!([$($x),+] $([$($y),+])*)
+    };
+    ($rows:expr) => {
+        $crate::__RowArray::into_matrix($rows)
+    };
+}
+
+/// Creates a matrix with a single row, i.e. a row vector.
+///
+/// # Examples
+/// ```rust
+/// let vec = qmat::row_vector![1, 2, 3];
+/// assert_eq!(vec.rows(), 1);
+/// assert_eq!(vec.cols(), 3);
+/// assert_eq!(vec[[0, 2]], 3);
+/// ```
+#[macro_export]
+macro_rules! row_vector {
+    ($($x:expr),+ $(,)?) => {
+        $crate::__matrix_from_rows!([$($x),+])
+    };
+}
+
+/// Creates a matrix with a single column, i.e. a column vector.
+///
+/// # Examples
+/// ```rust
+/// let vec = qmat::col_vector![1, 2, 3];
+/// assert_eq!(vec.rows(), 3);
+/// assert_eq!(vec.cols(), 1);
+/// assert_eq!(vec[[2, 0]], 3);
+/// ```
+#[macro_export]
+macro_rules! col_vector {
+    ($($x:expr),+ $(,)?) => {
+        $crate::__matrix_from_rows!($([$x])+)
+    };
+}
+
+/// Creates a matrix from bracketed rows, counting the dimensions when the macro is expanded.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __matrix_from_rows {
+    ($([$($x:expr),*])+) => {{
+        const M: usize = <[()]>::len(&[$($crate::__unit!([$($x),*])),+]);
+        const COLS: [usize; M] = [$(<[()]>::len(&[$($crate::__unit!($x)),*])),+];
+        const N: usize = COLS[0];
+        const _: () = {
+            assert!(N > 0, "matrix!: rows can't be empty");
+            let mut i = 1;
+            while i < M {
+                assert!(
+                    COLS[i] == N,
+                    "matrix!: all rows must have the same number of columns"
+                );
+                i += 1;
+            }
+        };
+        $crate::mat::Matrix::<_, M, N, { M * N }>::new([$($($x),*),+]).unwrap()
     }};
 }
 
+/// Converts an array of rows into the `Matrix` of the same shape, so `matrix!` can take the
+/// dimensions of an array expression from its type.
+#[diagnostic::on_unimplemented(
+    message = "`matrix!` can't take the dimensions of `{Self}`",
+    note = "arrays of rows are supported up to 16x16; use `Matrix::from_rows` for bigger ones"
+)]
+pub trait RowArray {
+    type Matrix;
+
+    fn into_matrix(self) -> Self::Matrix;
+}
+
+macro_rules! impl_row_array {
+    ($($M:literal)*) => {
+        $(impl_row_array!(@row $M; 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16);)*
+    };
+    (@row $M:literal; $($N:literal)*) => {
+        $(
+            impl<T: Copy> RowArray for [[T; $N]; $M] {
+                type Matrix = crate::mat::Matrix<T, $M, $N, { $M * $N }>;
+
+                fn into_matrix(self) -> Self::Matrix {
+                    crate::mat::Matrix::new(self.as_flattened().try_into().unwrap()).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_row_array!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16);
+
+/// Replaces an expression with `()`, so it can be counted in a const context.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unit {
+    ($x:expr) => {
+        ()
+    };
+}
+
 /// Creates a `$M`x`$M` [identity matrix](https://en.wikipedia.org/wiki/Identity_matrix) of type `$T`.
 ///
 /// # Examples
@@ -51,16 +187,16 @@ macro_rules! identity {
     }};
 }
 
-/// Creates a 1-dimensional matrix, i.e. a vector.
+/// Creates a column vector of `$M` elements from `$data`.
 ///
 /// # Examples
 /// ```rust
 /// let data = [0, 1, 2, 3];
-/// let mat = qmat::vector!(4, data);
-/// assert_eq!(mat[[0,0]], 0);
-/// assert_eq!(mat[[0,1]], 1);
-/// assert_eq!(mat[[0,2]], 2);
-/// assert_eq!(mat[[0,3]], 3);
+/// let vec = qmat::vector!(4, data);
+/// assert_eq!(vec[0], 0);
+/// assert_eq!(vec[1], 1);
+/// assert_eq!(vec[[2, 0]], 2);
+/// assert_eq!(vec[[3, 0]], 3);
 /// ```
 #[macro_export]
 macro_rules! vector {
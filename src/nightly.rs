@@ -0,0 +1,140 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::ops::{Add, Mul};
+
+use crate::{errors::NewMatrixError, identities::Zero, mat::Matrix};
+
+/// A matrix of `M` rows and `N` columns, without the redundant `LEN` generic.
+///
+/// This is the same type as `Matrix<T, M, N, { M * N }>`, so every method on [`Matrix`] is
+/// available on it too.
+/// Crates using it also need `#![feature(generic_const_exprs)]`.
+///
+/// # Examples
+/// ```rust
+/// # #![allow(incomplete_features)]
+/// # #![feature(generic_const_exprs)]
+/// use qmat::prelude::*;
+/// let mat: Mat<i32, 2, 3> = Mat::from_flat([0, 1, 2, 3, 4, 5]);
+/// assert_eq!(mat[[1, 2]], 5);
+/// ```
+pub type Mat<T, const M: usize, const N: usize> = Matrix<T, M, N, { M * N }>;
+
+impl<T, const M: usize, const N: usize> Mat<T, M, N>
+where
+    [(); M * N]:,
+{
+    /// Creates a new matrix from flat data.
+    /// Unlike `Matrix::new`, this can't fail because the length of `data` is always `M * N`.
+    ///
+    /// # Panics
+    /// * Never, as the generics are consistent by construction.
+    #[must_use]
+    pub fn from_flat(data: [T; M * N]) -> Self {
+        Matrix::new(data).unwrap()
+    }
+
+    /// Converts into a matrix with an explicit `LEN` generic.
+    ///
+    /// # Errors
+    /// * `NewMatrixError::IllegalGenerics` if `M * N != LEN`
+    ///
+    /// # Panics
+    /// * Never, as the lengths are checked beforehand.
+    pub fn into_len<const LEN: usize>(self) -> Result<Matrix<T, M, N, LEN>, NewMatrixError> {
+        if M * N != LEN {
+            return Err(NewMatrixError::IllegalGenerics {
+                m: M,
+                n: N,
+                len: LEN,
+            });
+        }
+        let mut data = self.into_flat_array().into_iter();
+        Matrix::new(std::array::from_fn(|_| data.next().unwrap()))
+    }
+}
+
+impl<T, const M: usize, const N: usize> Mat<T, M, N>
+where
+    T: Copy,
+    [(); M * N]:,
+{
+    /// Creates a new matrix from nested rows.
+    ///
+    /// # Panics
+    /// * Never, as the generics are consistent by construction.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use qmat::prelude::*;
+    /// let mat = Mat::from_nested([[0, 1, 2], [3, 4, 5]]);
+    /// assert_eq!(mat.rows(), 2);
+    /// assert_eq!(mat.cols(), 3);
+    /// ```
+    #[must_use]
+    pub fn from_nested(rows: [[T; N]; M]) -> Self {
+        Self::from_rows(rows).unwrap()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Mat<T, M, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+    [(); M * N]:,
+{
+    /// Matrix multiplication without any turbofish; the output shape is inferred from the inputs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use qmat::prelude::*;
+    ///
+    /// let a: Mat<i32, 2, 2> = Mat::from_flat([3, 4, 2, 1]);
+    /// let b: Mat<i32, 2, 2> = Mat::from_flat([1, 5, 3, 7]);
+    /// let output = a.matmul(&b);
+    ///
+    /// assert_eq!(*output.as_flat_array(), [15, 43, 5, 17]);
+    /// ```
+    #[must_use]
+    pub fn matmul<const O: usize>(&self, other: &Mat<T, N, O>) -> Mat<T, M, O>
+    where
+        [(); N * O]:,
+        [(); M * O]:,
+    {
+        self.multiply::<O, { N * O }, { M * O }>(other)
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN> {
+    /// Converts into the [`Mat`] form, dropping the `LEN` generic.
+    ///
+    /// # Panics
+    /// * Never, as `Matrix::new` guarantees that `LEN == M * N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #![allow(incomplete_features)]
+    /// # #![feature(generic_const_exprs)]
+    /// use qmat::prelude::*;
+    /// fn takes_mat<const M: usize, const N: usize>(mat: Mat<i32, M, N>) -> usize
+    /// where
+    ///     [(); M * N]:,
+    /// {
+    ///     mat.vol()
+    /// }
+    ///
+    /// let mat = matrix!(2, 3, [0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(takes_mat(mat.into_mat()), 6);
+    /// ```
+    #[must_use]
+    pub fn into_mat(self) -> Mat<T, M, N>
+    where
+        [(); M * N]:,
+    {
+        let mut data = self.into_flat_array().into_iter();
+        Mat::from_flat(std::array::from_fn(|_| data.next().unwrap()))
+    }
+}
@@ -0,0 +1,359 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Reading and writing numpy `.npy` files (requires the `npy` feature).
+//!
+//! Files are written as version 1.0, little-endian and in C (row-major) order.
+//! Files of any version, byte order or `fortran_order` can be read, as long as the dtype is exactly
+//! the element type (e.g. `<f8` for `f64`, `|u1` for `u8`) and the array is 1 or 2-dimensional.
+//! 1-dimensional arrays are read as column vectors.
+//! numpy has no 128-bit integers, so matrices of `i128` or `u128` can't be written:
+//! ```compile_fail
+//! use qmat::prelude::*;
+//! matrix![1i128, 2; 3, 4].to_npy(Vec::new()).unwrap();
+//! ```
+//!
+//! # Examples
+//! ```rust
+//! use qmat::prelude::*;
+//!
+//! let mat = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+//! let mut buf = Vec::new();
+//! mat.to_npy(&mut buf).unwrap();
+//! assert_eq!(&buf[..6], b"\x93NUMPY");
+//!
+//! let read = Matrix::<f64, 2, 3, 6>::from_npy(buf.as_slice()).unwrap();
+//! assert_eq!(read, mat);
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::{dmat::DMatrix, errors::NpyError, mat::Matrix};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// Element types that can be read from and written to `.npy` files.
+pub trait NpyElement: Copy {
+    /// The dtype's kind character: `i` for signed integers, `u` for unsigned and `f` for floats.
+    const KIND: char;
+
+    /// The size of an element in bytes.
+    const SIZE: usize = std::mem::size_of::<Self>();
+
+    /// Reads an element from exactly `SIZE` bytes.
+    fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// Appends the little-endian bytes of the element.
+    fn write_npy_bytes(self, out: &mut Vec<u8>);
+
+    /// The dtype written in the header, e.g. `<f8`.
+    #[must_use]
+    fn descr() -> String {
+        let order = if Self::SIZE == 1 { '|' } else { '<' };
+        format!("{order}{}{}", Self::KIND, Self::SIZE)
+    }
+}
+
+macro_rules! npy_element {
+    ($T:ty, $KIND:literal) => {
+        impl NpyElement for $T {
+            const KIND: char = $KIND;
+
+            fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let mut buf = [0; std::mem::size_of::<$T>()];
+                buf.copy_from_slice(bytes);
+                if big_endian {
+                    <$T>::from_be_bytes(buf)
+                } else {
+                    <$T>::from_le_bytes(buf)
+                }
+            }
+
+            fn write_npy_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+// Signed integers
+npy_element!(i8, 'i');
+npy_element!(i16, 'i');
+npy_element!(i32, 'i');
+npy_element!(i64, 'i');
+npy_element!(isize, 'i');
+
+// Unsigned integers
+npy_element!(u8, 'u');
+npy_element!(u16, 'u');
+npy_element!(u32, 'u');
+npy_element!(u64, 'u');
+npy_element!(usize, 'u');
+
+// Floating point
+npy_element!(f32, 'f');
+npy_element!(f64, 'f');
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: NpyElement,
+{
+    /// Writes the matrix as a 2-dimensional `.npy` array.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_npy(writer, self.as_flat_array(), M, N)
+    }
+
+    /// Reads a matrix from a `.npy` file.
+    ///
+    /// # Errors
+    /// * `NpyError::DimensionMismatch` if the array's shape isn't (`M`, `N`).
+    ///   A 1-dimensional array matches both a row and a column vector of its length.
+    /// * Same as `DMatrix::from_npy`.
+    pub fn from_npy<R: Read>(reader: R) -> Result<Self, NpyError> {
+        let (shape, data) = read_npy(reader)?;
+        let (rows, cols) = match shape[..] {
+            [len] if M == 1 => (1, len),
+            [len] => (len, 1),
+            [rows, cols] => (rows, cols),
+            _ => unreachable!("the shape was checked when reading"),
+        };
+
+        let mismatch = NpyError::DimensionMismatch {
+            expected: (M, N),
+            found: (rows, cols),
+        };
+        if rows != M || cols != N {
+            return Err(mismatch);
+        }
+        match data.try_into() {
+            Ok(data) => Matrix::new(data).map_err(|_| mismatch),
+            Err(_) => Err(mismatch),
+        }
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: NpyElement,
+{
+    /// Same as `Matrix::to_npy`.
+    ///
+    /// # Errors
+    /// * If writing fails.
+    pub fn to_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_npy(writer, self.as_flat_slice(), self.rows(), self.cols())
+    }
+
+    /// Reads a matrix from a `.npy` file, taking the dimensions from its shape.
+    ///
+    /// # Errors
+    /// * `NpyError::Io` if reading fails, including if the data is shorter than the shape says.
+    /// * `NpyError::InvalidMagic` if it doesn't start with the `.npy` magic string.
+    /// * `NpyError::UnsupportedVersion` if the format version is newer than 3.0.
+    /// * `NpyError::InvalidHeader` if the header can't be parsed, or its shape has more bytes than fit in a `usize`.
+    /// * `NpyError::DtypeMismatch` if the dtype isn't `T`.
+    /// * `NpyError::UnsupportedShape` if the array isn't 1 or 2-dimensional.
+    ///
+    /// # Panics
+    /// * If it fails to create the matrix.
+    pub fn from_npy<R: Read>(reader: R) -> Result<Self, NpyError> {
+        let (shape, data) = read_npy(reader)?;
+        let (rows, cols) = match shape[..] {
+            [len] => (len, 1),
+            [rows, cols] => (rows, cols),
+            _ => unreachable!("the shape was checked when reading"),
+        };
+        Ok(DMatrix::new(rows, cols, data).unwrap())
+    }
+}
+
+fn write_npy<T, W>(mut writer: W, data: &[T], rows: usize, cols: usize) -> io::Result<()>
+where
+    T: NpyElement,
+    W: Write,
+{
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': ({rows}, {cols}), }}",
+        T::descr()
+    );
+
+    // the header is padded with spaces so the data is 64-byte aligned, and ends in a newline
+    let preamble = if header.len() + 11 > usize::from(u16::MAX) {
+        12
+    } else {
+        10
+    };
+    let padding = (64 - (preamble + header.len() + 1) % 64) % 64;
+    header.extend(std::iter::repeat_n(' ', padding));
+    header.push('\n');
+
+    writer.write_all(MAGIC)?;
+    if let Ok(len) = u16::try_from(header.len()) {
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&len.to_le_bytes())?;
+    } else {
+        let len = u32::try_from(header.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "header is too long"))?;
+        writer.write_all(&[2, 0])?;
+        writer.write_all(&len.to_le_bytes())?;
+    }
+    writer.write_all(header.as_bytes())?;
+
+    let mut bytes = Vec::with_capacity(data.len() * T::SIZE);
+    for &elem in data {
+        elem.write_npy_bytes(&mut bytes);
+    }
+    writer.write_all(&bytes)?;
+    writer.flush()
+}
+
+/// Returns the shape and the row-major elements.
+fn read_npy<T, R>(mut reader: R) -> Result<(Vec<usize>, Vec<T>), NpyError>
+where
+    T: NpyElement,
+    R: Read,
+{
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if magic[..6] != MAGIC[..] {
+        return Err(NpyError::InvalidMagic);
+    }
+
+    let header_len = match (magic[6], magic[7]) {
+        (1, 0) => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+            usize::from(u16::from_le_bytes(len))
+        }
+        (2 | 3, 0) => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+            usize::try_from(u32::from_le_bytes(len))
+                .map_err(|_| NpyError::InvalidHeader("header is too long".to_string()))?
+        }
+        (major, minor) => return Err(NpyError::UnsupportedVersion(major, minor)),
+    };
+    let mut header = vec![0; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header)
+        .map_err(|_| NpyError::InvalidHeader("header isn't valid text".to_string()))?;
+
+    let header = parse_header(&header)?;
+    let big_endian = dtype_is_big_endian::<T>(&header.descr)?;
+
+    let (rows, cols) = match header.shape[..] {
+        [len] => (len, 1),
+        [rows, cols] => (rows, cols),
+        _ => return Err(NpyError::UnsupportedShape(header.shape)),
+    };
+
+    let len = rows
+        .checked_mul(cols)
+        .and_then(|len| len.checked_mul(T::SIZE))
+        .ok_or_else(|| NpyError::InvalidHeader("the shape is too big".to_string()))?;
+    // the shape comes from the file, so only allocate for data that's actually there
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    let elems: Vec<T> = bytes
+        .chunks_exact(T::SIZE)
+        .map(|chunk| T::from_npy_bytes(chunk, big_endian))
+        .collect();
+
+    let data = if header.fortran_order {
+        // column-major, so transpose into row-major
+        let mut data = Vec::with_capacity(elems.len());
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(elems[col * rows + row]);
+            }
+        }
+        data
+    } else {
+        elems
+    };
+
+    Ok((header.shape, data))
+}
+
+/// Checks that the dtype is `T`, returning whether it's big-endian.
+fn dtype_is_big_endian<T: NpyElement>(descr: &str) -> Result<bool, NpyError> {
+    let mismatch = || NpyError::DtypeMismatch {
+        expected: T::descr(),
+        found: descr.to_string(),
+    };
+
+    let (big_endian, rest) = match descr.as_bytes().first() {
+        Some(b'<') => (false, &descr[1..]),
+        Some(b'>') => (true, &descr[1..]),
+        Some(b'|' | b'=') => (cfg!(target_endian = "big"), &descr[1..]),
+        _ => (cfg!(target_endian = "big"), descr),
+    };
+
+    let mut chars = rest.chars();
+    let kind = chars.next().ok_or_else(mismatch)?;
+    let size: usize = chars.as_str().parse().map_err(|_| mismatch())?;
+    if kind != T::KIND || size != T::SIZE {
+        return Err(mismatch());
+    }
+
+    Ok(big_endian)
+}
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// Parses the Python dict literal, e.g. `{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }`.
+fn parse_header(header: &str) -> Result<Header, NpyError> {
+    let invalid = || NpyError::InvalidHeader(header.trim_end().to_string());
+
+    let descr = value_of(header, "descr").ok_or_else(invalid)?;
+    let quote = descr.chars().next().filter(|c| matches!(c, '\'' | '"'));
+    let descr = quote
+        .and_then(|quote| descr[1..].split(quote).next())
+        .ok_or_else(invalid)?
+        .to_string();
+
+    let fortran_order = value_of(header, "fortran_order").ok_or_else(invalid)?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return Err(invalid());
+    };
+
+    let shape = value_of(header, "shape")
+        .and_then(|shape| shape.strip_prefix('('))
+        .and_then(|shape| shape.split(')').next())
+        .ok_or_else(invalid)?;
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid())?;
+
+    Ok(Header {
+        descr,
+        fortran_order,
+        shape,
+    })
+}
+
+/// Returns the text after `'key':`, with leading whitespace removed.
+fn value_of<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header
+        .find(&format!("'{key}'"))
+        .or_else(|| header.find(&format!("\"{key}\"")))?;
+    let rest = header[start + key.len() + 2..].trim_start();
+    Some(rest.strip_prefix(':')?.trim_start())
+}
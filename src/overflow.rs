@@ -0,0 +1,317 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub,
+    WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+use crate::mat::{ColVector, Matrix};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy,
+{
+    /// Applies `f` to every pair of elements, or returns `None` as soon as it does.
+    fn try_zip_with<F>(&self, other: &Self, f: F) -> Option<Self>
+    where
+        F: Fn(&T, &T) -> Option<T>,
+    {
+        let mut out = *self;
+        for (x, y) in out.iter_mut().zip(other.as_flat_array()) {
+            *x = f(x, y)?;
+        }
+        Some(out)
+    }
+
+    /// Applies `f` to every pair of elements.
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> T,
+    {
+        let mut out = *self;
+        for (x, y) in out.iter_mut().zip(other.as_flat_array()) {
+            *x = f(x, y);
+        }
+        out
+    }
+
+    /// Returns a matrix where element `i` is `self[i] + rhs[i]`, or `None` if any of them overflows.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = matrix![1u8, 2; 3, 4];
+    /// assert_eq!(a.checked_add(&a), Some(matrix![2, 4; 6, 8]));
+    /// assert_eq!(a.checked_add(&matrix![255, 0; 0, 0]), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self>
+    where
+        T: CheckedAdd,
+    {
+        self.try_zip_with(rhs, T::checked_add)
+    }
+
+    /// Returns a matrix where element `i` is `self[i] - rhs[i]`, or `None` if any of them overflows.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self>
+    where
+        T: CheckedSub,
+    {
+        self.try_zip_with(rhs, T::checked_sub)
+    }
+
+    /// Same as `mul_scalar`, but returns `None` if any element overflows.
+    #[must_use]
+    pub fn checked_mul_scalar(&self, scalar: T) -> Option<Self>
+    where
+        T: CheckedMul,
+    {
+        let mut out = *self;
+        for x in &mut out {
+            *x = x.checked_mul(&scalar)?;
+        }
+        Some(out)
+    }
+
+    /// Same as `multiply`, but returns `None` if any product or partial sum overflows.
+    ///
+    /// # Panics
+    /// * If `RES_LEN` isn't `M * O`, like `multiply`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = matrix![1i8, 2; 3, 4];
+    /// assert_eq!(a.checked_mul::<2, 4, 4>(&a), Some(matrix![7, 10; 15, 22]));
+    /// assert_eq!(a.mul_scalar(8).checked_mul::<2, 4, 4>(&a), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul<const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+    ) -> Option<Matrix<T, M, O, RES_LEN>>
+    where
+        T: Zero + CheckedAdd + CheckedMul,
+    {
+        let mut out = Matrix::<T, M, O, RES_LEN>::new([T::zero(); RES_LEN])
+            .expect("RES_LEN must be the number of elements in the product");
+        let (lhs, rhs) = (self.as_flat_array(), other.as_flat_array());
+        for (i, elem) in out.iter_mut().enumerate() {
+            let (row, col) = (i / O, i % O);
+            for j in 0..N {
+                *elem = elem.checked_add(&lhs[row * N + j].checked_mul(&rhs[j * O + col])?)?;
+            }
+        }
+        Some(out)
+    }
+
+    /// Returns a matrix where element `i` is `self[i] + rhs[i]`, wrapping around on overflow.
+    #[must_use]
+    pub fn wrapping_add(&self, rhs: &Self) -> Self
+    where
+        T: WrappingAdd,
+    {
+        self.zip_with(rhs, T::wrapping_add)
+    }
+
+    /// Returns a matrix where element `i` is `self[i] - rhs[i]`, wrapping around on overflow.
+    #[must_use]
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self
+    where
+        T: WrappingSub,
+    {
+        self.zip_with(rhs, T::wrapping_sub)
+    }
+
+    /// Same as `mul_scalar`, but wraps around on overflow.
+    #[must_use]
+    pub fn wrapping_mul_scalar(&self, scalar: T) -> Self
+    where
+        T: WrappingMul,
+    {
+        let mut out = *self;
+        for x in &mut out {
+            *x = x.wrapping_mul(&scalar);
+        }
+        out
+    }
+
+    /// Same as `multiply`, but wraps around on overflow.
+    ///
+    /// Since wrapping arithmetic is modular, the result is the true product modulo `T::MAX + 1`.
+    ///
+    /// # Panics
+    /// * If `RES_LEN` isn't `M * O`, like `multiply`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = matrix![16u8, 0; 0, 1];
+    /// assert_eq!(a.wrapping_mul::<2, 4, 4>(&a), matrix![0, 0; 0, 1]);
+    /// ```
+    #[must_use]
+    pub fn wrapping_mul<const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+    ) -> Matrix<T, M, O, RES_LEN>
+    where
+        T: Zero + WrappingAdd + WrappingMul,
+    {
+        let mut out = Matrix::<T, M, O, RES_LEN>::new([T::zero(); RES_LEN])
+            .expect("RES_LEN must be the number of elements in the product");
+        let (lhs, rhs) = (self.as_flat_array(), other.as_flat_array());
+        for (i, elem) in out.iter_mut().enumerate() {
+            let (row, col) = (i / O, i % O);
+            for j in 0..N {
+                *elem = elem.wrapping_add(&lhs[row * N + j].wrapping_mul(&rhs[j * O + col]));
+            }
+        }
+        out
+    }
+
+    /// Returns a matrix where element `i` is `self[i] + rhs[i]`, clamped to `T`'s bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = matrix![100i8, -100; 1, 2];
+    /// assert_eq!(a.saturating_add(&a), matrix![127, -128; 2, 4]);
+    /// ```
+    #[must_use]
+    pub fn saturating_add(&self, rhs: &Self) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        self.zip_with(rhs, T::saturating_add)
+    }
+
+    /// Returns a matrix where element `i` is `self[i] - rhs[i]`, clamped to `T`'s bounds.
+    #[must_use]
+    pub fn saturating_sub(&self, rhs: &Self) -> Self
+    where
+        T: SaturatingSub,
+    {
+        self.zip_with(rhs, T::saturating_sub)
+    }
+
+    /// Same as `mul_scalar`, but clamps every element to `T`'s bounds.
+    ///
+    /// There's no saturating matrix product, since clamping the partial sums would make
+    /// the result depend on the order they're added in.
+    #[must_use]
+    pub fn saturating_mul_scalar(&self, scalar: T) -> Self
+    where
+        T: SaturatingMul,
+    {
+        let mut out = *self;
+        for x in &mut out {
+            *x = x.saturating_mul(&scalar);
+        }
+        out
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Copy + Zero,
+{
+    /// Same as `dot`, but returns `None` if any product or partial sum overflows.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = col_vector![100u8, 1];
+    /// assert_eq!(a.checked_dot(&col_vector![2, 3]), Some(203));
+    /// assert_eq!(a.checked_dot(&col_vector![3, 3]), None);
+    /// ```
+    #[must_use]
+    pub fn checked_dot(&self, other: &Self) -> Option<T>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        self.as_flat_array()
+            .iter()
+            .zip(other.as_flat_array())
+            .try_fold(T::zero(), |acc, (x, y)| acc.checked_add(&x.checked_mul(y)?))
+    }
+
+    /// Same as `dot`, but wraps around on overflow.
+    #[must_use]
+    pub fn wrapping_dot(&self, other: &Self) -> T
+    where
+        T: WrappingAdd + WrappingMul,
+    {
+        self.as_flat_array()
+            .iter()
+            .zip(other.as_flat_array())
+            .fold(T::zero(), |acc, (x, y)| {
+                acc.wrapping_add(&x.wrapping_mul(y))
+            })
+    }
+}
+
+impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
+where
+    T: Copy + PartialEq + num_traits::One + Zero + CheckedSub + CheckedMul + CheckedDiv,
+{
+    /// Returns the determinant, or `None` if computing it overflows.
+    ///
+    /// Uses fraction-free (Bareiss) elimination, so every division is exact and
+    /// the values stored between steps are minors of the matrix, which grow much slower than `det`'s.
+    /// Each step still multiplies two of them before dividing, though,
+    /// so `None` can be returned even when the determinant and every minor fit in `T`:
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// // det is 65536, but 65536 * 65537 doesn't fit in an i32
+    /// assert_eq!(matrix![65536i32, 65536; 65536, 65537].checked_det(), None);
+    /// ```
+    /// For unsigned types, it's also returned if an intermediate value would be negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![2i32, -3, 1; 2, 0, -1; 1, 4, 5];
+    /// assert_eq!(mat.checked_det(), Some(49));
+    /// let big = matrix![i32::MAX, 1; 1, i32::MAX];
+    /// assert_eq!(big.checked_det(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_det(&self) -> Option<T> {
+        if M == 0 {
+            return Some(T::one());
+        }
+
+        let mut mat = *self;
+        let mut negate = false;
+        let mut prev = T::one();
+        for k in 0..M - 1 {
+            if mat[[k, k]] == T::zero() {
+                let pivot = (k + 1..M).find(|&row| mat[[row, k]] != T::zero());
+                let Some(pivot) = pivot else {
+                    return Some(T::zero());
+                };
+                for col in 0..M {
+                    (mat[[k, col]], mat[[pivot, col]]) = (mat[[pivot, col]], mat[[k, col]]);
+                }
+                negate = !negate;
+            }
+
+            for row in k + 1..M {
+                for col in k + 1..M {
+                    let lhs = mat[[row, col]].checked_mul(&mat[[k, k]])?;
+                    let rhs = mat[[row, k]].checked_mul(&mat[[k, col]])?;
+                    mat[[row, col]] = lhs.checked_sub(&rhs)?.checked_div(&prev)?;
+                }
+            }
+            prev = mat[[k, k]];
+        }
+
+        let det = mat[[M - 1, M - 1]];
+        if negate {
+            T::zero().checked_sub(&det)
+        } else {
+            Some(det)
+        }
+    }
+}
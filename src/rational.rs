@@ -0,0 +1,121 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::ops::Div;
+
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::Signed;
+
+use crate::{errors::MatrixOperationError, identities::Ring, mat::Matrix};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Ring + Div<Output = T>,
+{
+    /// Puts the matrix in [reduced row echelon form](https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form)
+    /// by Gauss-Jordan elimination, pivoting on the first nonzero element of each column, and returns its rank.
+    ///
+    /// Only exact for fields with exact division, like `Ratio`.
+    pub(crate) fn rref_in_place(&mut self) -> usize {
+        let mut rank = 0;
+        for col in 0..N {
+            if rank == M {
+                break;
+            }
+            let Some(pivot) = (rank..M).find(|&row| !self[[row, col]].is_zero()) else {
+                continue;
+            };
+            if pivot != rank {
+                for j in 0..N {
+                    (self[[rank, j]], self[[pivot, j]]) = (self[[pivot, j]], self[[rank, j]]);
+                }
+            }
+
+            let scale = self[[rank, col]];
+            for j in col..N {
+                self[[rank, j]] = self[[rank, j]] / scale;
+            }
+
+            for row in (0..M).filter(|&row| row != rank) {
+                let factor = self[[row, col]];
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in col..N {
+                    self[[row, j]] = self[[row, j]] - factor * self[[rank, j]];
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Integer + Copy,
+{
+    /// Converts every element to a [`Ratio`] with a denominator of 1.
+    ///
+    /// # Panics
+    /// * If `LEN` isn't `M * N`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use qmat::prelude::*;
+    /// let mat = matrix![1, 2; 3, 4].to_rational();
+    /// assert_eq!(mat[[1, 0]], Ratio::from_integer(3));
+    /// ```
+    #[must_use]
+    pub fn to_rational(&self) -> Matrix<Ratio<T>, M, N, LEN> {
+        Matrix::new(self.as_flat_array().map(Ratio::from_integer)).expect("LEN must be M * N")
+    }
+
+    /// Returns the [reduced row echelon form](https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form),
+    /// computed exactly with rationals.
+    ///
+    /// # Panics
+    /// * If a numerator or denominator overflows `T` (in debug builds).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use qmat::prelude::*;
+    /// let rref = matrix![2, 4, 1; 1, 2, 1].rref_exact();
+    /// assert_eq!(rref, matrix![1, 2, 0; 0, 0, 1].to_rational());
+    /// ```
+    #[must_use]
+    pub fn rref_exact(&self) -> Matrix<Ratio<T>, M, N, LEN> {
+        let mut rref = self.to_rational();
+        rref.rref_in_place();
+        rref
+    }
+}
+
+impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
+where
+    T: Integer + Signed + Copy,
+{
+    /// Returns the inverse, computed exactly with rationals.
+    /// Integers aren't a `Field`, so this is how to invert an integer matrix.
+    ///
+    /// # Errors
+    /// * `MatrixOperationError::Singular` if `self.det() == 0`.
+    ///
+    /// # Panics
+    /// * If a numerator or denominator overflows `T` (in debug builds).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use num_rational::Ratio;
+    /// use qmat::prelude::*;
+    /// let inv = matrix![2, 1; 1, 1].inverse_exact().unwrap();
+    /// assert_eq!(inv, matrix![1, -1; -1, 2].to_rational());
+    /// let inv = matrix![3, 0; 0, 4].inverse_exact().unwrap();
+    /// assert_eq!(inv[[0, 0]], Ratio::new(1, 3));
+    /// ```
+    pub fn inverse_exact(&self) -> Result<Matrix<Ratio<T>, M, M, LEN>, MatrixOperationError> {
+        self.to_rational().inverse()
+    }
+}
@@ -0,0 +1,267 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Matrix products over other [semirings](https://en.wikipedia.org/wiki/Semiring), e.g. for graph problems.
+//!
+//! A semiring swaps out the `+` and `*` that `multiply` uses for two other operations.
+//! If `mat` is a graph's adjacency matrix, then `mat.pow_in::<S>(k)` gives, for every pair of vertices:
+//! * the shortest walk of `k` edges with [`MinPlus`],
+//! * the longest walk of `k` edges with [`MaxPlus`],
+//! * whether there's a walk of `k` edges with [`Boolean`],
+//! * the number of walks of `k` edges with [`Counting`].
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{prelude::*, semiring::MinPlus};
+//!
+//! let inf = f64::INFINITY;
+//! // 0 -> 1 costs 1, 1 -> 2 costs 2, 0 -> 2 costs 5
+//! let graph = matrix![
+//!     0.0, 1.0, 5.0;
+//!     inf, 0.0, 2.0;
+//!     inf, inf, 0.0
+//! ];
+//! let shortest = graph.pow_in::<MinPlus<f64>>(2);
+//! assert_eq!(shortest[[0, 2]], 3.0);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{identities::Ring, mat::Matrix};
+
+/// A pair of operations to multiply matrices with.
+///
+/// The implementing type is just a marker; the values themselves are `Elem`s.
+/// `add` and `mul` should both be associative, `add` should be commutative,
+/// `mul` should distribute over `add`, and `zero` should annihilate under `mul`.
+pub trait Semiring {
+    type Elem: Copy;
+
+    /// The identity for `add`.
+    fn zero() -> Self::Elem;
+    /// The identity for `mul`.
+    fn one() -> Self::Elem;
+    fn add(a: Self::Elem, b: Self::Elem) -> Self::Elem;
+    fn mul(a: Self::Elem, b: Self::Elem) -> Self::Elem;
+}
+
+/// The usual `+` and `*`, which counts walks in a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counting<T>(PhantomData<T>);
+
+/// `min` as addition and `+` as multiplication, with infinity (or `T::MAX`) as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinPlus<T>(PhantomData<T>);
+
+/// `max` as addition and `+` as multiplication, with negative infinity (or `T::MIN`) as zero.
+/// Only implemented for signed types, since unsigned ones have no value below the `0` that's needed for one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxPlus<T>(PhantomData<T>);
+
+/// `||` as addition and `&&` as multiplication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boolean;
+
+impl<T: Ring> Semiring for Counting<T> {
+    type Elem = T;
+
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::identity()
+    }
+    fn add(a: T, b: T) -> T {
+        a + b
+    }
+    fn mul(a: T, b: T) -> T {
+        a * b
+    }
+}
+
+impl Semiring for Boolean {
+    type Elem = bool;
+
+    fn zero() -> bool {
+        false
+    }
+    fn one() -> bool {
+        true
+    }
+    fn add(a: bool, b: bool) -> bool {
+        a || b
+    }
+    fn mul(a: bool, b: bool) -> bool {
+        a && b
+    }
+}
+
+/*
+ * Tropical semirings
+ */
+macro_rules! tropical_int {
+    ($S:ident, $T:ty, $ZERO:expr, $ADD:ident) => {
+        impl Semiring for $S<$T> {
+            type Elem = $T;
+
+            fn zero() -> $T {
+                $ZERO
+            }
+            fn one() -> $T {
+                0
+            }
+            fn add(a: $T, b: $T) -> $T {
+                a.$ADD(b)
+            }
+            fn mul(a: $T, b: $T) -> $T {
+                // zero stands in for an infinity, so has to stay one
+                if a == $ZERO || b == $ZERO {
+                    $ZERO
+                } else {
+                    a.saturating_add(b)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! tropical_float {
+    ($S:ident, $T:ty, $ZERO:expr, $ADD:ident) => {
+        impl Semiring for $S<$T> {
+            type Elem = $T;
+
+            fn zero() -> $T {
+                $ZERO
+            }
+            fn one() -> $T {
+                0.0
+            }
+            fn add(a: $T, b: $T) -> $T {
+                a.$ADD(b)
+            }
+            fn mul(a: $T, b: $T) -> $T {
+                a + b
+            }
+        }
+    };
+}
+
+macro_rules! signed {
+    ($($T:ty),*) => {$(
+        tropical_int!(MinPlus, $T, <$T>::MAX, min);
+        tropical_int!(MaxPlus, $T, <$T>::MIN, max);
+    )*};
+}
+
+macro_rules! unsigned {
+    ($($T:ty),*) => {$(
+        tropical_int!(MinPlus, $T, <$T>::MAX, min);
+    )*};
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+tropical_float!(MinPlus, f32, f32::INFINITY, min);
+tropical_float!(MinPlus, f64, f64::INFINITY, min);
+tropical_float!(MaxPlus, f32, f32::NEG_INFINITY, max);
+tropical_float!(MaxPlus, f64, f64::NEG_INFINITY, max);
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy,
+{
+    /// Same as `multiply`, but with `S`'s operations instead of `+` and `*`.
+    ///
+    /// Turbofish `::<S, O, Q, RES_LEN>` where `O`, `Q` and `RES_LEN` are the same as for `multiply`.
+    ///
+    /// # Panics
+    /// * If `RES_LEN` isn't `M * O`, like `multiply`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::{prelude::*, semiring::Boolean};
+    /// let a = matrix![true, false; false, false];
+    /// let b = matrix![false, true; true, false];
+    /// assert_eq!(
+    ///     a.multiply_in::<Boolean, 2, 4, 4>(&b),
+    ///     matrix![false, true; false, false]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn multiply_in<S, const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+    ) -> Matrix<T, M, O, RES_LEN>
+    where
+        S: Semiring<Elem = T>,
+    {
+        assert_eq!(
+            M * O,
+            RES_LEN,
+            "RES_LEN must be the number of elements in the product"
+        );
+        let lhs = self.as_flat_array();
+        let rhs = other.as_flat_array();
+        Matrix::new(std::array::from_fn(|i| {
+            let (row, col) = (i / O, i % O);
+            (0..N).fold(S::zero(), |acc, j| {
+                S::add(acc, S::mul(lhs[row * N + j], rhs[j * O + col]))
+            })
+        }))
+        .expect("RES_LEN is checked above")
+    }
+}
+
+impl<T, const M: usize, const LEN: usize> Matrix<T, M, M, LEN>
+where
+    T: Copy,
+{
+    /// Returns the identity matrix of `S`, with `S::one()` on the diagonal and `S::zero()` everywhere else.
+    ///
+    /// # Panics
+    /// * If `LEN` isn't `M * M`.
+    #[must_use]
+    pub fn identity_in<S>() -> Self
+    where
+        S: Semiring<Elem = T>,
+    {
+        Self::new(std::array::from_fn(|i| {
+            if i / M == i % M {
+                S::one()
+            } else {
+                S::zero()
+            }
+        }))
+        .expect("LEN must be M * M")
+    }
+
+    /// Raises the matrix to the power of `exp` with `S`'s operations, by repeated squaring.
+    /// The 0th power is `identity_in::<S>()`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::{prelude::*, semiring::Counting};
+    /// // a triangle, with an edge each way between every pair of vertices
+    /// let graph = matrix![0u64, 1, 1; 1, 0, 1; 1, 1, 0];
+    /// // the number of closed walks of length 3 from each vertex
+    /// assert_eq!(graph.pow_in::<Counting<u64>>(3)[[0, 0]], 2);
+    /// ```
+    #[must_use]
+    pub fn pow_in<S>(&self, mut exp: u32) -> Self
+    where
+        S: Semiring<Elem = T>,
+    {
+        let mut out = Self::identity_in::<S>();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = out.multiply_in::<S, M, LEN, LEN>(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.multiply_in::<S, M, LEN, LEN>(&base);
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,388 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! Alternative serde representations, for use with `#[serde(with = "...")]`.
+//!
+//! | Module | `Matrix` | `DMatrix` |
+//! |--------|----------|-----------|
+//! | [`nested`] | `[[1, 2], [3, 4]]` | `[[1, 2], [3, 4]]` |
+//! | [`flat`] | `[1, 2, 3, 4]` | `[2, 2, [1, 2, 3, 4]]` |
+//! | [`structured`] | `{"rows": 2, "cols": 2, "data": [1, 2, 3, 4]}` | same as `Matrix` |
+//!
+//! [`structured`] is also what the plain `Serialize`/`Deserialize` impls use.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "qmat::serde::nested")]
+//!     weights: Matrix<i32, 2, 2, 4>,
+//! }
+//!
+//! let config = Config { weights: matrix![1, 2; 3, 4] };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"weights":[[1,2],[3,4]]}"#);
+//! ```
+
+use std::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{self, MapAccess, SeqAccess, Unexpected, Visitor},
+    Deserialize,
+};
+
+use crate::{dmat::DMatrix, mat::Matrix};
+
+/// The matrix types that the representations in this module can be used with.
+pub trait SerdeMatrix<T>: Sized {
+    /// The (rows, cols) of the matrix, if they're known from the type alone.
+    const SHAPE: Option<(usize, usize)>;
+
+    /// Returns the (rows, cols) of the matrix.
+    fn shape(&self) -> (usize, usize);
+
+    /// Returns the elements in row-major order.
+    fn elements(&self) -> &[T];
+
+    /// Creates the matrix from its dimensions and row-major elements.
+    ///
+    /// # Errors
+    /// * If the dimensions don't match the type, or the number of elements doesn't match the dimensions.
+    fn from_parts<E: de::Error>(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, E>;
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> SerdeMatrix<T> for Matrix<T, M, N, LEN> {
+    const SHAPE: Option<(usize, usize)> = Some((M, N));
+
+    fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    fn elements(&self) -> &[T] {
+        self.as_flat_array()
+    }
+
+    fn from_parts<E: de::Error>(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, E> {
+        if rows != M {
+            return Err(E::invalid_value(
+                Unexpected::Unsigned(rows as u64),
+                &format!("{M} rows").as_str(),
+            ));
+        }
+        if cols != N {
+            return Err(E::invalid_value(
+                Unexpected::Unsigned(cols as u64),
+                &format!("{N} cols").as_str(),
+            ));
+        }
+
+        let data: [T; LEN] = data.try_into().map_err(|data: Vec<T>| {
+            E::invalid_length(data.len(), &format!("{LEN} elements").as_str())
+        })?;
+
+        Matrix::new(data).map_err(|_| E::custom(format!("{M} * {N} != {LEN}")))
+    }
+}
+
+impl<T> SerdeMatrix<T> for DMatrix<T> {
+    const SHAPE: Option<(usize, usize)> = None;
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn elements(&self) -> &[T] {
+        self.as_flat_slice()
+    }
+
+    fn from_parts<E: de::Error>(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, E> {
+        let len = data.len();
+        DMatrix::new(rows, cols, data)
+            .map_err(|_| E::invalid_length(len, &format!("{rows} * {cols} elements").as_str()))
+    }
+}
+
+/// `[[1, 2], [3, 4]]`: a sequence of rows.
+pub mod nested {
+    use ::serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{de, SerdeMatrix};
+
+    /// # Errors
+    /// * If the serializer fails.
+    pub fn serialize<X, T, S>(mat: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Serialize,
+        S: Serializer,
+    {
+        let (rows, cols) = mat.shape();
+        let mut seq = serializer.serialize_seq(Some(rows))?;
+        if cols == 0 {
+            // `chunks` can't split into empty rows, but the row count still has to round-trip
+            for _ in 0..rows {
+                seq.serialize_element::<[T]>(&[])?;
+            }
+        } else {
+            for row in mat.elements().chunks(cols) {
+                seq.serialize_element(row)?;
+            }
+        }
+        seq.end()
+    }
+
+    /// # Errors
+    /// * If the rows aren't all the same length, or don't match the matrix type.
+    pub fn deserialize<'de, X, T, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let nested: Vec<Vec<T>> = Vec::deserialize(deserializer)?;
+        let rows = nested.len();
+        let cols = nested.first().map_or(0, Vec::len);
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for (i, row) in nested.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(de::Error::custom(format!(
+                    "row {i} has {} elements, expected {cols}",
+                    row.len()
+                )));
+            }
+            data.extend(row);
+        }
+
+        X::from_parts(rows, cols, data)
+    }
+}
+
+/// `[1, 2, 3, 4]`: just the elements in row-major order, as a tuple.
+///
+/// A `DMatrix` doesn't know its shape from its type, so it's written as the tuple `(rows, cols, data)`.
+pub mod flat {
+    use std::marker::PhantomData;
+
+    use ::serde::{ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{de, fmt, SeqAccess, SerdeMatrix, Visitor};
+
+    /// # Errors
+    /// * If the serializer fails.
+    pub fn serialize<X, T, S>(mat: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Serialize,
+        S: Serializer,
+    {
+        if X::SHAPE.is_some() {
+            let mut tup = serializer.serialize_tuple(mat.elements().len())?;
+            for elem in mat.elements() {
+                tup.serialize_element(elem)?;
+            }
+            tup.end()
+        } else {
+            let (rows, cols) = mat.shape();
+            let mut tup = serializer.serialize_tuple(3)?;
+            tup.serialize_element(&rows)?;
+            tup.serialize_element(&cols)?;
+            tup.serialize_element(mat.elements())?;
+            tup.end()
+        }
+    }
+
+    /// # Errors
+    /// * If the number of elements doesn't match the matrix type.
+    pub fn deserialize<'de, X, T, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let visitor = FlatVisitor { __boo: PhantomData };
+        match X::SHAPE {
+            Some((rows, cols)) => deserializer.deserialize_tuple(rows * cols, visitor),
+            None => deserializer.deserialize_tuple(3, visitor),
+        }
+    }
+
+    struct FlatVisitor<X, T> {
+        __boo: PhantomData<(X, T)>,
+    }
+
+    impl<'de, X, T> Visitor<'de> for FlatVisitor<X, T>
+    where
+        X: SerdeMatrix<T>,
+        T: Deserialize<'de>,
+    {
+        type Value = X;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            match X::SHAPE {
+                Some((rows, cols)) => write!(formatter, "a tuple of {} elements", rows * cols),
+                None => formatter.write_str("a tuple of (rows, cols, data)"),
+            }
+        }
+
+        fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+        where
+            V: SeqAccess<'de>,
+        {
+            if let Some((rows, cols)) = X::SHAPE {
+                let len = rows * cols;
+                let mut data = Vec::with_capacity(len);
+                for i in 0..len {
+                    data.push(
+                        seq.next_element()?
+                            .ok_or_else(|| de::Error::invalid_length(i, &self))?,
+                    );
+                }
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(len + 1, &self));
+                }
+                X::from_parts(rows, cols, data)
+            } else {
+                let rows: usize = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let cols: usize = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let data: Vec<T> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                X::from_parts(rows, cols, data)
+            }
+        }
+    }
+}
+
+/// `{"rows": 2, "cols": 2, "data": [1, 2, 3, 4]}`: the dimensions and the elements in row-major order.
+///
+/// Also accepts the sequence form `[rows, cols, data]`.
+pub mod structured {
+    use std::marker::PhantomData;
+
+    use ::serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{MatrixVisitor, SerdeMatrix};
+
+    const FIELDS: &[&str] = &["rows", "cols", "data"];
+
+    /// # Errors
+    /// * If the serializer fails.
+    pub fn serialize<X, T, S>(mat: &X, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Serialize,
+        S: Serializer,
+    {
+        let (rows, cols) = mat.shape();
+        let mut s = serializer.serialize_struct("Matrix", 3)?;
+
+        s.serialize_field("rows", &rows)?;
+        s.serialize_field("cols", &cols)?;
+        s.serialize_field("data", mat.elements())?;
+
+        s.end()
+    }
+
+    /// # Errors
+    /// * If `rows` or `cols` don't match the matrix type, or if `data` has the wrong number of elements.
+    pub fn deserialize<'de, X, T, D>(deserializer: D) -> Result<X, D::Error>
+    where
+        X: SerdeMatrix<T>,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let visitor = MatrixVisitor { __boo: PhantomData };
+        deserializer.deserialize_struct("Matrix", FIELDS, visitor)
+    }
+}
+
+struct MatrixVisitor<X, T> {
+    __boo: PhantomData<(X, T)>,
+}
+
+impl<'de, X, T> Visitor<'de> for MatrixVisitor<X, T>
+where
+    X: SerdeMatrix<T>,
+    T: Deserialize<'de>,
+{
+    type Value = X;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match X::SHAPE {
+            Some((rows, cols)) => {
+                write!(formatter, "struct Matrix with {rows} rows and {cols} cols")
+            }
+            None => formatter.write_str("struct Matrix"),
+        }
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let rows: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let cols: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data: Vec<T> = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+        X::from_parts(rows, cols, data)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut rows = None;
+        let mut cols = None;
+        let mut data = None;
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Rows => {
+                    if rows.is_some() {
+                        return Err(de::Error::duplicate_field("rows"));
+                    }
+                    rows = Some(map.next_value()?);
+                }
+                Field::Cols => {
+                    if cols.is_some() {
+                        return Err(de::Error::duplicate_field("cols"));
+                    }
+                    cols = Some(map.next_value()?);
+                }
+                Field::Data => {
+                    if data.is_some() {
+                        return Err(de::Error::duplicate_field("data"));
+                    }
+                    data = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let rows = rows.ok_or_else(|| de::Error::missing_field("rows"))?;
+        let cols = cols.ok_or_else(|| de::Error::missing_field("cols"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+        X::from_parts(rows, cols, data)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Rows,
+    Cols,
+    Data,
+}
@@ -0,0 +1,108 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use num_traits::Zero;
+
+use crate::{dmat::DMatrix, position::Position};
+
+/// A sparse matrix in coordinate (triplet) form, whose dimensions are only known at runtime.
+///
+/// Only the entries that were pushed are stored; every other element is zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(Position, T)>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Creates a new `CooMatrix` of the given dimensions with no entries.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds an entry. Entries at the same position are summed when converting to a dense matrix.
+    ///
+    /// # Panics
+    /// * If `pos` is out of bounds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::{prelude::*, sparse::CooMatrix};
+    /// let mut mat = CooMatrix::new(2, 2);
+    /// mat.push(Position(1, 0), 5);
+    /// assert_eq!(mat.to_dense()[[1, 0]], 5);
+    /// ```
+    pub fn push(&mut self, pos: Position, val: T) {
+        assert!(
+            pos.0 < self.rows && pos.1 < self.cols,
+            "position {pos} is out of bounds"
+        );
+        self.entries.push((pos, val));
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the stored entries, in the order they were pushed.
+    #[must_use]
+    pub fn entries(&self) -> &[(Position, T)] {
+        &self.entries
+    }
+}
+
+impl<T> CooMatrix<T>
+where
+    T: Zero + Copy,
+{
+    /// Converts into a dense matrix, summing any entries at the same position.
+    ///
+    /// # Panics
+    /// * If `rows * cols` overflows `usize`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let len = self
+            .rows
+            .checked_mul(self.cols)
+            .expect("the dense matrix has more than usize::MAX elements");
+        let mut dense = DMatrix::new(self.rows, self.cols, vec![T::zero(); len]).unwrap();
+        for &(pos, val) in &self.entries {
+            dense[pos] = dense[pos] + val;
+        }
+        dense
+    }
+
+    /// Creates a sparse matrix from the non-zero elements of a dense one, in row-major order.
+    #[must_use]
+    pub fn from_dense(dense: &DMatrix<T>) -> Self {
+        let mut sparse = Self::new(dense.rows(), dense.cols());
+        for row in 0..dense.rows() {
+            for col in 0..dense.cols() {
+                let val = dense[[row, col]];
+                if !val.is_zero() {
+                    sparse.entries.push((Position(row, col), val));
+                }
+            }
+        }
+        sparse
+    }
+}
@@ -0,0 +1,130 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+//! More accurate, opt-in summation for float dot products, products and reductions.
+//!
+//! `dot` and `multiply` add their terms in order, so the rounding error grows with the
+//! number of terms and cancellation can wipe out the result entirely.
+//! The `_with` methods here take a [`Summation`] to pick a more accurate algorithm instead.
+//!
+//! # Examples
+//! ```rust
+//! use qmat::{prelude::*, summation::Summation};
+//!
+//! let a = col_vector![1e16, 1.0, -1e16];
+//! let b = col_vector![1.0, 1.0, 1.0];
+//! assert_eq!(a.dot(&b), 0.0);
+//! assert_eq!(a.dot_with(&b, Summation::KahanBabuska), 1.0);
+//! ```
+
+use num_traits::Float;
+
+use crate::{
+    mat::{ColVector, Matrix},
+    math::{compensated_dot, kahan_babuska_sum, pairwise_dot, pairwise_sum},
+};
+
+/// How to add up the terms of a sum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Summation {
+    /// In order, like `dot` and `multiply` do.
+    #[default]
+    Naive,
+    /// By recursively splitting the terms in half, so the error grows with `log(n)` instead of `n`.
+    /// Costs about the same as `Naive`.
+    Pairwise,
+    /// In order, while keeping track of the rounding error of each addition (Neumaier's variant of Kahan summation).
+    /// The error doesn't grow with `n`, but the products in a dot product are still rounded.
+    KahanBabuska,
+    /// Same as `KahanBabuska`, but also recovers the rounding error of each product with a fused multiply-add,
+    /// giving a result as accurate as if it had been computed in twice the precision.
+    /// Only differs from `KahanBabuska` for dot products, and is slow without hardware FMA.
+    Compensated,
+}
+
+impl Summation {
+    fn dot<T: Float>(self, a: &[T], b: &[T]) -> T {
+        match self {
+            Self::Naive => a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y),
+            Self::Pairwise => pairwise_dot(a, b),
+            Self::KahanBabuska => kahan_babuska_sum(a.iter().zip(b).map(|(&x, &y)| x * y)),
+            Self::Compensated => compensated_dot(a, b),
+        }
+    }
+
+    fn sum<T: Float>(self, xs: &[T]) -> T {
+        match self {
+            Self::Naive => xs.iter().fold(T::zero(), |acc, &x| acc + x),
+            Self::Pairwise => pairwise_sum(xs),
+            Self::KahanBabuska | Self::Compensated => kahan_babuska_sum(xs.iter().copied()),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Float,
+{
+    /// Same as `multiply`, but sums each element's products using `summation`.
+    ///
+    /// # Panics
+    /// * If `RES_LEN` isn't `M * O`, like `multiply`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::{prelude::*, summation::Summation};
+    /// let a = matrix![1e16, 1.0, -1e16; 1.0, 2.0, 3.0];
+    /// let b = col_vector![1.0, 1.0, 1.0];
+    /// let res = a.multiply_with::<1, 3, 2>(&b, Summation::KahanBabuska);
+    /// assert_eq!(res, col_vector![1.0, 6.0]);
+    /// ```
+    #[must_use]
+    pub fn multiply_with<const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+        summation: Summation,
+    ) -> Matrix<T, M, O, RES_LEN> {
+        assert_eq!(
+            M * O,
+            RES_LEN,
+            "RES_LEN must be the number of elements in the product"
+        );
+        let lhs = self.as_flat_array();
+        let rhs = other.as_flat_array();
+        Matrix::new(std::array::from_fn(|i| {
+            let (row, col) = (i / O, i % O);
+            let col: [T; N] = std::array::from_fn(|j| rhs[j * O + col]);
+            summation.dot(&lhs[row * N..(row + 1) * N], &col)
+        }))
+        .expect("RES_LEN is checked above")
+    }
+
+    /// Returns the sum of all the elements, added using `summation`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::{prelude::*, summation::Summation};
+    /// let mat = matrix![0.1, 0.2; 0.3, 0.4];
+    /// assert_eq!(mat.sum_with(Summation::KahanBabuska), 1.0);
+    /// ```
+    #[must_use]
+    pub fn sum_with(&self, summation: Summation) -> T {
+        summation.sum(self.as_flat_array())
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Float,
+{
+    /// Same as `dot`, but sums the products using `summation`.
+    #[must_use]
+    pub fn dot_with(&self, other: &Self, summation: Summation) -> T {
+        summation.dot(self.as_flat_array(), other.as_flat_array())
+    }
+
+    /// Same as `norm`, but sums the squares using `summation`.
+    #[must_use]
+    pub fn norm_with(&self, summation: Summation) -> T {
+        self.dot_with(self, summation).sqrt()
+    }
+}
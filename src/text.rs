@@ -0,0 +1,264 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::{
+    fmt::{self, Alignment, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    dmat::DMatrix,
+    errors::{ParseMatrixError, ParseMatrixErrorKind},
+    mat::Matrix,
+};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Display for Matrix<T, M, N, LEN>
+where
+    T: Display,
+{
+    /// Writes each row on its own line, with the columns aligned.
+    /// The width, fill, alignment and precision flags are applied to every element.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat = matrix![1.0, -2.5; 30.0, 4.0];
+    /// assert_eq!(format!("{mat}"), "[ 1 -2.5]\n[30    4]");
+    /// assert_eq!(format!("{mat:.2}"), "[ 1.00 -2.50]\n[30.00  4.00]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_grid(f, self.as_flat_array(), N)
+    }
+}
+
+impl<T> Display for DMatrix<T>
+where
+    T: Display,
+{
+    /// Same as for `Matrix`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_grid(f, self.as_flat_slice(), self.cols())
+    }
+}
+
+/// Writes row-major `data` with `cols` columns as aligned, bracketed rows.
+fn write_grid<T: Display>(f: &mut Formatter<'_>, data: &[T], cols: usize) -> fmt::Result {
+    if cols == 0 {
+        return f.write_str("[]");
+    }
+
+    let cells: Vec<String> = data
+        .iter()
+        .map(|elem| match f.precision() {
+            Some(precision) => format!("{elem:.precision$}"),
+            None => elem.to_string(),
+        })
+        .collect();
+
+    let mut widths = vec![f.width().unwrap_or(0); cols];
+    for (i, cell) in cells.iter().enumerate() {
+        widths[i % cols] = widths[i % cols].max(cell.chars().count());
+    }
+
+    for (row, chunk) in cells.chunks(cols).enumerate() {
+        if row > 0 {
+            f.write_str("\n")?;
+        }
+        f.write_str("[")?;
+        for (col, cell) in chunk.iter().enumerate() {
+            if col > 0 {
+                f.write_str(" ")?;
+            }
+            write_padded(f, cell, widths[col])?;
+        }
+        f.write_str("]")?;
+    }
+
+    Ok(())
+}
+
+fn write_padded(f: &mut Formatter<'_>, cell: &str, width: usize) -> fmt::Result {
+    let padding = width - cell.chars().count();
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill().to_string();
+    f.write_str(&fill.repeat(before))?;
+    f.write_str(cell)?;
+    f.write_str(&fill.repeat(after))
+}
+
+impl<T, const M: usize, const N: usize, const LEN: usize> FromStr for Matrix<T, M, N, LEN>
+where
+    T: FromStr,
+{
+    type Err = ParseMatrixError;
+
+    /// Parses the format written by `Display`, or MATLAB-style `[1 2; 3 4]`.
+    ///
+    /// Rows are separated by newlines or semicolons, and may each be wrapped in brackets.
+    /// Elements are separated by whitespace or commas.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let mat: Matrix<i32, 2, 2, 4> = "[1 2; 3 4]".parse().unwrap();
+    /// assert_eq!(mat, matrix![1, 2; 3, 4]);
+    ///
+    /// let err = "[1 2; 3 x]".parse::<Matrix<i32, 2, 2, 4>>().unwrap_err();
+    /// assert_eq!((err.line, err.col), (1, 9));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = parse_grid(s)?;
+
+        // point to the first row that doesn't fit, or the end if there are too few
+        let (line, col) = if grid.cols == N {
+            grid.row_starts.get(M).copied().unwrap_or(grid.end)
+        } else {
+            grid.row_starts.first().copied().unwrap_or(grid.end)
+        };
+        let mismatch = ParseMatrixError {
+            line,
+            col,
+            kind: ParseMatrixErrorKind::DimensionMismatch {
+                expected: (M, N),
+                found: (grid.rows(), grid.cols),
+            },
+        };
+
+        if grid.rows() != M || grid.cols != N {
+            return Err(mismatch);
+        }
+        match grid.data.try_into() {
+            Ok(data) => Matrix::new(data).map_err(|_| mismatch),
+            Err(_) => Err(mismatch),
+        }
+    }
+}
+
+impl<T> FromStr for DMatrix<T>
+where
+    T: FromStr,
+{
+    type Err = ParseMatrixError;
+
+    /// Same as for `Matrix`, but the dimensions are taken from the string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = parse_grid(s)?;
+        let rows = grid.rows();
+        Ok(DMatrix::new(rows, grid.cols, grid.data)
+            .unwrap_or_else(|_| unreachable!("rows were checked to be the same length")))
+    }
+}
+
+/// The parsed elements, plus where each row started so that later errors can point to them.
+struct Grid<T> {
+    cols: usize,
+    data: Vec<T>,
+    row_starts: Vec<(usize, usize)>,
+    end: (usize, usize),
+}
+
+impl<T> Grid<T> {
+    fn rows(&self) -> usize {
+        self.row_starts.len()
+    }
+}
+
+/// Finishes the current row, checking it against the first.
+fn end_row<T>(grid: &mut Grid<T>, row_len: &mut usize) -> Result<(), ParseMatrixError> {
+    if *row_len == 0 {
+        return Ok(());
+    }
+    if grid.rows() == 1 {
+        grid.cols = *row_len;
+    } else if *row_len != grid.cols {
+        let (line, col) = grid.row_starts[grid.rows() - 1];
+        return Err(ParseMatrixError {
+            line,
+            col,
+            kind: ParseMatrixErrorKind::RaggedRow {
+                expected: grid.cols,
+                found: *row_len,
+            },
+        });
+    }
+    *row_len = 0;
+    Ok(())
+}
+
+fn parse_grid<T: FromStr>(s: &str) -> Result<Grid<T>, ParseMatrixError> {
+    let mut grid = Grid {
+        cols: 0,
+        data: Vec::new(),
+        row_starts: Vec::new(),
+        end: (1, 1),
+    };
+    let mut row_len = 0;
+    let mut open_brackets = Vec::new();
+
+    let mut chars = s.chars().peekable();
+    let (mut line, mut col) = (1, 1);
+
+    while let Some(c) = chars.next() {
+        match c {
+            '[' => open_brackets.push((line, col)),
+            ']' => {
+                if open_brackets.pop().is_none() {
+                    return Err(ParseMatrixError {
+                        line,
+                        col,
+                        kind: ParseMatrixErrorKind::UnbalancedBracket,
+                    });
+                }
+                end_row(&mut grid, &mut row_len)?;
+            }
+            ';' | '\n' => end_row(&mut grid, &mut row_len)?,
+            ',' => {}
+            c if c.is_whitespace() => {}
+            c => {
+                let start = (line, col);
+                let mut token = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '[' | ']' | ';' | ',') {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                    col += 1;
+                }
+
+                let elem = token.parse().map_err(|_| ParseMatrixError {
+                    line: start.0,
+                    col: start.1,
+                    kind: ParseMatrixErrorKind::InvalidElement(token),
+                })?;
+                if row_len == 0 {
+                    grid.row_starts.push(start);
+                }
+                grid.data.push(elem);
+                row_len += 1;
+            }
+        }
+
+        if c == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
+    }
+
+    if let Some(&(line, col)) = open_brackets.last() {
+        return Err(ParseMatrixError {
+            line,
+            col,
+            kind: ParseMatrixErrorKind::UnbalancedBracket,
+        });
+    }
+    end_row(&mut grid, &mut row_len)?;
+    grid.end = (line, col);
+
+    Ok(grid)
+}
@@ -0,0 +1,207 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::{
+    iter::Sum,
+    ops::{Mul, Sub},
+};
+
+use num_traits::Float;
+
+use crate::mat::{ColVector, Matrix};
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Copy + Mul + Sum<<T as Mul>::Output>,
+{
+    /// Returns the squared [Euclidean norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#Euclidean_norm) of the vector.
+    /// Avoids the square root, so also works for integers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let vec = col_vector![3, 4];
+    /// assert_eq!(vec.norm_squared(), 25);
+    /// ```
+    #[must_use]
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Copy + Mul,
+{
+    /// Returns the [outer product](https://en.wikipedia.org/wiki/Outer_product) of `self` and `other`.
+    ///
+    /// Turbofish `::<O, RES_LEN>` where
+    /// * `O` is the number of elements in `other`,
+    /// * `RES_LEN` is the number of elements in the resulting matrix (`M` * `O`).
+    ///
+    /// See `try_outer` for a version that doesn't panic.
+    ///
+    /// # Panics
+    /// * If `RES_LEN != M * O`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = col_vector![1, 2];
+    /// let b = col_vector![3, 4, 5];
+    /// let outer = a.outer::<3, 6>(&b);
+    /// assert_eq!(*outer.as_flat_array(), [3, 4, 5, 6, 8, 10]);
+    /// ```
+    #[must_use]
+    pub fn outer<const O: usize, const RES_LEN: usize>(
+        &self,
+        other: &ColVector<T, O>,
+    ) -> Matrix<T, M, O, RES_LEN>
+    where
+        T: Mul<Output = T>,
+    {
+        self.try_outer(other).unwrap()
+    }
+}
+
+impl<T> ColVector<T, 3>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Returns the [cross product](https://en.wikipedia.org/wiki/Cross_product) of two 3-dimensional vectors.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let x = col_vector![1, 0, 0];
+    /// let y = col_vector![0, 1, 0];
+    /// assert_eq!(x.cross(&y), col_vector![0, 0, 1]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // a 3x1 matrix always holds 3 elements
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+        .unwrap()
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Float + Sum,
+{
+    /// Returns the [Euclidean norm](https://en.wikipedia.org/wiki/Norm_(mathematics)#Euclidean_norm) (i.e. the length) of the vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let vec = col_vector![3.0, 4.0];
+    /// assert_eq!(vec.norm(), 5.0);
+    /// ```
+    #[must_use]
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns a vector in the same direction with a norm of 1.
+    /// A zero vector gives `NaN` elements; see [`ColVector::try_normalize`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let vec = col_vector![0.0, 4.0];
+    /// assert_eq!(vec.normalize(), col_vector![0.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        self.mul_scalar(self.norm().recip())
+    }
+
+    /// Same as [`ColVector::normalize`], but returns `None` for a zero vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// assert!(col_vector![0.0, 0.0].try_normalize().is_none());
+    /// assert!(col_vector![1.0, 0.0].try_normalize().is_some());
+    /// ```
+    #[must_use]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            None
+        } else {
+            Some(self.mul_scalar(norm.recip()))
+        }
+    }
+
+    /// Returns the angle between the two vectors, in radians.
+    /// The angle to a zero vector is undefined, so it's `NaN` if either vector is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let x = col_vector![1.0, 0.0];
+    /// let y = col_vector![0.0, 2.0];
+    /// assert_eq!(x.angle_between(&y), std::f64::consts::FRAC_PI_2);
+    /// assert!(x.angle_between(&col_vector![0.0, 0.0]).is_nan());
+    /// ```
+    #[must_use]
+    pub fn angle_between(&self, other: &Self) -> T {
+        let norms = self.norm() * other.norm();
+        if norms.is_zero() {
+            // otherwise the clamping below would turn 0 / 0 into an angle of pi
+            return T::nan();
+        }
+        let cos = self.dot(other) / norms;
+        // rounding errors can put it just outside of acos' domain
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    /// Returns the [projection](https://en.wikipedia.org/wiki/Vector_projection) of `self` onto `onto`.
+    /// Projecting onto a zero vector gives `NaN` elements.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let vec = col_vector![2.0, 3.0];
+    /// let onto = col_vector![4.0, 0.0];
+    /// assert_eq!(vec.project_onto(&onto), col_vector![2.0, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn project_onto(&self, onto: &Self) -> Self {
+        onto.mul_scalar(self.dot(onto) / onto.norm_squared())
+    }
+
+    /// Returns the [rejection](https://en.wikipedia.org/wiki/Vector_projection#Vector_rejection_2) of `self` from `from`,
+    /// i.e. the part of `self` orthogonal to `from`.
+    /// Rejecting from a zero vector gives `NaN` elements, like `project_onto`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let vec = col_vector![2.0, 3.0];
+    /// let from = col_vector![4.0, 0.0];
+    /// assert_eq!(vec.reject_from(&from), col_vector![0.0, 3.0]);
+    /// ```
+    #[must_use]
+    pub fn reject_from(&self, from: &Self) -> Self {
+        *self - self.project_onto(from)
+    }
+
+    /// [Linearly interpolates](https://en.wikipedia.org/wiki/Linear_interpolation) between `self` (`t = 0`) and `other` (`t = 1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = col_vector![0.0, 10.0];
+    /// let b = col_vector![4.0, 20.0];
+    /// assert_eq!(a.lerp(&b, 0.25), col_vector![1.0, 12.5]);
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        *self + (*other - *self).mul_scalar(t)
+    }
+}
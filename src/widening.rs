@@ -0,0 +1,80 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::{iter::Sum, ops::Mul};
+
+use crate::{
+    mat::{ColVector, Matrix},
+    math::arr_dot,
+};
+
+impl<T, const M: usize, const N: usize, const LEN: usize> Matrix<T, M, N, LEN>
+where
+    T: Copy,
+{
+    /// Same as `multiply`, but converts every element to `W` first,
+    /// so that the products and sums are computed (and returned) in the wider type.
+    ///
+    /// # Panics
+    /// * If `RES_LEN` isn't `M * O`, like `multiply`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let pixels = matrix![200u8, 100; 50, 255];
+    /// let weights = matrix![3u8, 0; 0, 2];
+    /// let res: Matrix<u32, 2, 2, 4> = pixels.widening_multiply::<u32, 2, 4, 4>(&weights);
+    /// assert_eq!(res, matrix![600, 200; 150, 510]);
+    /// ```
+    #[must_use]
+    pub fn widening_multiply<W, const O: usize, const Q: usize, const RES_LEN: usize>(
+        &self,
+        other: &Matrix<T, N, O, Q>,
+    ) -> Matrix<W, M, O, RES_LEN>
+    where
+        W: From<T> + Copy + Mul + Sum<<W as Mul>::Output>,
+    {
+        assert_eq!(
+            M * O,
+            RES_LEN,
+            "RES_LEN must be the number of elements in the product"
+        );
+        let lhs = self.as_flat_array();
+        let rhs = other.as_flat_array();
+        Matrix::new(std::array::from_fn(|i| {
+            let (row, col) = (i / O, i % O);
+            arr_dot::<W, N>(
+                std::array::from_fn(|j| W::from(lhs[row * N + j])),
+                std::array::from_fn(|j| W::from(rhs[j * O + col])),
+            )
+        }))
+        .expect("RES_LEN is checked above")
+    }
+}
+
+impl<T, const M: usize> ColVector<T, M>
+where
+    T: Copy,
+{
+    /// Same as `dot`, but converts every element to `W` first,
+    /// so that the products and sum are computed (and returned) in the wider type.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use qmat::prelude::*;
+    /// let a = col_vector![-128i8, 127];
+    /// assert_eq!(a.widening_dot::<i32>(&a), 32513);
+    /// let b = col_vector![1e30f32, 1e30];
+    /// assert!(b.dot(&b).is_infinite());
+    /// assert!(b.widening_dot::<f64>(&b).is_finite());
+    /// ```
+    #[must_use]
+    pub fn widening_dot<W>(&self, other: &Self) -> W
+    where
+        W: From<T> + Copy + Mul + Sum<<W as Mul>::Output>,
+    {
+        arr_dot::<W, M>(
+            self.as_flat_array().map(W::from),
+            other.as_flat_array().map(W::from),
+        )
+    }
+}
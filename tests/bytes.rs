@@ -0,0 +1,57 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::{PodCastError, Zeroable};
+use qmat::prelude::*;
+
+#[test]
+fn round_trip() {
+    let mat = matrix![1.5f32, -2.0, 3.25; 0.0, 4.0, -1e-3];
+    let bytes = mat.as_bytes();
+    assert_eq!(bytes.len(), 6 * 4);
+    assert_eq!(&bytes[4..8], &(-2.0f32).to_ne_bytes());
+
+    // copy into an f32 buffer so the bytes are aligned
+    let buf: Vec<f32> = bytemuck::cast_slice(bytes).to_vec();
+    let view = Matrix::<f32, 2, 3, 6>::from_bytes(bytemuck::cast_slice(&buf)).unwrap();
+    assert_eq!(view, &mat);
+}
+
+#[test]
+fn as_bytes_mut() {
+    let mut mat = matrix![0u16, 0; 0, 0];
+    mat.as_bytes_mut()[2..4].copy_from_slice(&513u16.to_ne_bytes());
+    assert_eq!(mat, matrix![0, 513; 0, 0]);
+}
+
+#[test]
+fn pod_and_zeroable() {
+    let zeroed: Matrix<i32, 2, 2, 4> = Zeroable::zeroed();
+    assert_eq!(zeroed, matrix![0, 0; 0, 0]);
+
+    let mat: Matrix<u32, 1, 2, 2> = bytemuck::cast([7u32, 8]);
+    assert_eq!(mat, row_vector![7, 8]);
+    let mats = [matrix![1u8, 2; 3, 4]; 2];
+    assert_eq!(
+        bytemuck::cast_slice::<_, u8>(&mats),
+        &[1, 2, 3, 4, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn errors() {
+    let buf = [0u32; 4];
+    let bytes: &[u8] = bytemuck::cast_slice(&buf);
+    assert_eq!(
+        Matrix::<u32, 1, 3, 3>::from_bytes(bytes),
+        Err(PodCastError::SizeMismatch)
+    );
+    assert_eq!(
+        Matrix::<u32, 1, 3, 3>::cast_slice(bytes),
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(
+        Matrix::<u32, 1, 3, 3>::from_bytes(&bytes[1..13]),
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    );
+    assert_eq!(Matrix::<u32, 2, 2, 4>::cast_slice(bytes).unwrap().len(), 1);
+}
@@ -0,0 +1,110 @@
+use num_complex::Complex;
+use qmat::{errors::MatrixOperationError, identities::Identity, prelude::*};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn approx_eq<const M: usize, const N: usize, const LEN: usize>(
+    a: &Matrix<Complex<f64>, M, N, LEN>,
+    b: &Matrix<Complex<f64>, M, N, LEN>,
+) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| (x - y).norm() < 1e-12)
+}
+
+#[test]
+fn identity() {
+    let id: Matrix<Complex<f64>, 2, 2, 4> = Matrix::identity();
+    assert_eq!(
+        id,
+        matrix![c(1.0, 0.0), c(0.0, 0.0); c(0.0, 0.0), c(1.0, 0.0)]
+    );
+    assert_eq!(Complex::<f32>::identity(), Complex::new(1.0, 0.0));
+}
+
+#[test]
+fn conjugate_and_adjoint() {
+    let mat =
+        matrix![c(1.0, 2.0), c(3.0, -4.0), c(0.0, 1.0); c(5.0, 0.0), c(-1.0, -1.0), c(2.0, 2.0)];
+    assert_eq!(
+        mat.conjugate(),
+        matrix![c(1.0, -2.0), c(3.0, 4.0), c(0.0, -1.0); c(5.0, 0.0), c(-1.0, 1.0), c(2.0, -2.0)]
+    );
+    assert_eq!(
+        mat.adjoint(),
+        matrix![c(1.0, -2.0), c(5.0, 0.0); c(3.0, 4.0), c(-1.0, 1.0); c(0.0, -1.0), c(2.0, -2.0)]
+    );
+    assert_eq!(mat.adjoint().adjoint(), mat);
+}
+
+#[test]
+fn hermitian() {
+    let pauli_y = matrix![c(0.0, 0.0), c(0.0, -1.0); c(0.0, 1.0), c(0.0, 0.0)];
+    assert!(pauli_y.is_hermitian(0.0));
+    // the diagonal of a hermitian matrix has to be real
+    assert!(!matrix![c(1.0, 1.0), c(0.0, 0.0); c(0.0, 0.0), c(1.0, 0.0)].is_hermitian(0.0));
+
+    let almost = matrix![c(1.0, 0.0), c(2.0, 1e-9); c(2.0, 0.0), c(1.0, 0.0)];
+    assert!(!almost.is_hermitian(0.0));
+    assert!(almost.is_hermitian(1e-6));
+}
+
+#[test]
+fn unitary() {
+    let pauli_y = matrix![c(0.0, 0.0), c(0.0, -1.0); c(0.0, 1.0), c(0.0, 0.0)];
+    assert!(pauli_y.is_unitary(0.0));
+
+    let theta = 0.3f64;
+    let phase = matrix![c(1.0, 0.0), c(0.0, 0.0); c(0.0, 0.0), Complex::from_polar(1.0, theta)];
+    assert!(phase.is_unitary(1e-12));
+    assert!(!matrix![c(1.0, 0.0), c(1.0, 0.0); c(0.0, 0.0), c(1.0, 0.0)].is_unitary(1e-12));
+}
+
+#[test]
+fn det() {
+    let mat = matrix![c(1.0, 1.0), c(2.0, 0.0); c(0.0, -1.0), c(3.0, 2.0)];
+    // (1 + i)(3 + 2i) - 2(-i) = 1 + 5i + 2i
+    assert_eq!(mat.det(), c(1.0, 7.0));
+}
+
+#[test]
+fn inverse() {
+    let mat = matrix![
+        c(2.0, 1.0), c(0.0, 0.0), c(1.0, 0.0);
+        c(0.0, 0.0), c(0.0, 3.0), c(0.0, 0.0);
+        c(1.0, -1.0), c(0.0, 0.0), c(1.0, 0.0)
+    ];
+    let inv = mat.inverse().unwrap();
+    let id: Matrix<Complex<f64>, 3, 3, 9> = Matrix::identity();
+    assert!(approx_eq(&mat.multiply::<3, 9, 9>(&inv), &id));
+    assert!(approx_eq(&inv.multiply::<3, 9, 9>(&mat), &id));
+
+    // the adjoint of a unitary matrix is its inverse
+    let pauli_y = matrix![c(0.0, 0.0), c(0.0, -1.0); c(0.0, 1.0), c(0.0, 0.0)];
+    assert_eq!(pauli_y.inverse(), Ok(pauli_y.adjoint()));
+
+    let singular = matrix![
+        c(1.0, 1.0), c(2.0, 2.0), c(0.0, 0.0);
+        c(1.0, 0.0), c(2.0, 0.0), c(0.0, 0.0);
+        c(0.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)
+    ];
+    assert_eq!(singular.inverse(), Err(MatrixOperationError::Singular));
+}
+
+#[test]
+fn solve() {
+    let mat = matrix![
+        c(1e-3, 0.0), c(1.0, 1.0), c(0.0, 2.0);
+        c(4.0, -1.0), c(0.0, 0.0), c(1.0, 0.0);
+        c(0.0, 1.0), c(2.0, 0.0), c(3.0, 3.0)
+    ];
+    let x = col_vector![c(1.0, -2.0), c(0.5, 0.0), c(0.0, 1.0)];
+    let b = mat.multiply::<1, 3, 3>(&x);
+    assert!(approx_eq(&mat.solve(&b).unwrap(), &x));
+
+    assert_eq!(
+        matrix![c(1.0, 0.0), c(2.0, 0.0); c(2.0, 0.0), c(4.0, 0.0)]
+            .solve(&col_vector![c(1.0, 0.0), c(0.0, 0.0)]),
+        Err(MatrixOperationError::Singular)
+    );
+}
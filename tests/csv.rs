@@ -0,0 +1,87 @@
+#![cfg(feature = "csv")]
+
+use qmat::{csv::CsvOptions, errors::CsvError, prelude::*};
+
+fn to_string<F: FnOnce(&mut Vec<u8>)>(write: F) -> String {
+    let mut buf = Vec::new();
+    write(&mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let mat = matrix![1.5, -2.0; 3.25, 4.0; 0.0, 1e-3];
+    let csv = to_string(|buf| mat.to_csv(buf, &CsvOptions::default()).unwrap());
+    assert_eq!(csv, "1.5,-2\n3.25,4\n0,0.001\n");
+    let read = Matrix::<f64, 3, 2, 6>::from_csv(csv.as_bytes(), &CsvOptions::default()).unwrap();
+    assert_eq!(read, mat);
+}
+
+#[test]
+fn delimiter_and_header() {
+    let options = CsvOptions {
+        delimiter: b';',
+        has_header: true,
+        column_names: vec!["x".to_string(), "y".to_string()],
+    };
+    let mat = matrix![1, 2, 3; 4, 5, 6];
+    let csv = to_string(|buf| mat.to_csv(buf, &options).unwrap());
+    assert_eq!(csv, "x;y;2\n1;2;3\n4;5;6\n");
+    let read = Matrix::<i32, 2, 3, 6>::from_csv(csv.as_bytes(), &options).unwrap();
+    assert_eq!(read, mat);
+}
+
+#[test]
+fn trims_whitespace() {
+    let csv = "1, 2\n 3 ,4\n";
+    let read = Matrix::<u8, 2, 2, 4>::from_csv(csv.as_bytes(), &CsvOptions::default()).unwrap();
+    assert_eq!(read, matrix![1, 2; 3, 4]);
+}
+
+#[test]
+fn invalid_element() {
+    let options = CsvOptions {
+        has_header: true,
+        ..CsvOptions::default()
+    };
+    let csv = "a,b\n1,2\n3,oops\n";
+    match DMatrix::<i32>::from_csv(csv.as_bytes(), &options) {
+        Err(CsvError::InvalidElement { pos, text }) => {
+            assert_eq!(pos, Position(1, 1));
+            assert_eq!(text, "oops");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn ragged_row() {
+    let csv = "1,2\n3\n";
+    let err = DMatrix::<i32>::from_csv(csv.as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert_eq!(err.to_string(), "row 1 has 1 elements, expected 2");
+}
+
+#[test]
+fn dimension_mismatch() {
+    let csv = "1,2\n3,4\n";
+    let err = Matrix::<i32, 1, 2, 2>::from_csv(csv.as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert!(matches!(
+        err,
+        CsvError::DimensionMismatch {
+            expected: (1, 2),
+            found: (2, 2)
+        }
+    ));
+}
+
+#[test]
+fn dmatrix_round_trip() {
+    let mat = DMatrix::new(2, 2, vec![-1, 0, 10, 200]).unwrap();
+    let options = CsvOptions {
+        delimiter: b'\t',
+        ..CsvOptions::default()
+    };
+    let csv = to_string(|buf| mat.to_csv(buf, &options).unwrap());
+    assert_eq!(csv, "-1\t0\n10\t200\n");
+    assert_eq!(DMatrix::from_csv(csv.as_bytes(), &options).unwrap(), mat);
+}
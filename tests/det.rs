@@ -27,3 +27,17 @@ fn det_3x3_nonzero_c() {
     let mat = qmat::matrix!(3, 3, [1, 2, 3, 3, 2, 1, 2, 1, 3]);
     assert_eq!(mat.det(), -12)
 }
+
+#[test]
+fn det_3x3_swap_non_adjacent_rows() {
+    // the first pivot comes from the last row
+    let mat = qmat::matrix!(3, 3, [0, 1, 2, 0, 3, 1, 4, 0, 0]);
+    assert_eq!(mat.det(), -20)
+}
+
+#[test]
+fn det_3x3_pivot_zeroed_by_elimination() {
+    // eliminating the first column zeroes the middle row's second element
+    let mat = qmat::matrix!(3, 3, [1.0, 2.0, 3.0, 2.0, 4.0, 1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(mat.det(), -5.0)
+}
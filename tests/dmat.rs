@@ -0,0 +1,72 @@
+use qmat::{errors::NewMatrixError, prelude::*};
+
+#[test]
+fn new() {
+    let mat = DMatrix::new(2, 3, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(mat.rows(), 2);
+    assert_eq!(mat.cols(), 3);
+    assert_eq!(mat.vol(), 6);
+    assert_eq!(mat[[1, 2]], 5);
+    assert_eq!(mat[Position(0, 1)], 1);
+}
+
+#[test]
+fn new_with_illegal_arguments() {
+    assert_eq!(
+        DMatrix::new(2, 3, vec![0; 5]),
+        Err(NewMatrixError::IllegalArguments {
+            expected: 6,
+            found: 5
+        })
+    );
+    assert_eq!(
+        DMatrix::<i32>::new(1 << 32, 1 << 32, vec![]),
+        Err(NewMatrixError::IllegalArguments {
+            expected: usize::MAX,
+            found: 0
+        })
+    );
+    assert_eq!(
+        DMatrix::from_rows(vec![vec![0, 1], vec![2]]),
+        Err(NewMatrixError::RaggedRows {
+            row: 1,
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn index_mut() {
+    let mut mat = DMatrix::from_rows(vec![vec![0, 1], vec![2, 3]]).unwrap();
+    mat[[1, 0]] = 7;
+    mat[Position(0, 1)] = 9;
+    assert_eq!(mat.as_flat_slice(), &[0, 9, 7, 3]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let mat = DMatrix::new(2, 2, vec![0, 1, 2, 3]).unwrap();
+    let _ = mat[[0, 2]];
+}
+
+#[test]
+fn conversions() {
+    let mat = matrix![1, 2, 3; 4, 5, 6];
+    let dmat = DMatrix::from(mat);
+    assert_eq!(dmat.rows(), 2);
+    assert_eq!(dmat.as_flat_slice(), mat.as_flat_array());
+
+    let back: Matrix<i32, 2, 3, 6> = dmat.clone().try_into().unwrap();
+    assert_eq!(back, mat);
+
+    let wrong: Result<Matrix<i32, 3, 2, 6>, _> = dmat.try_into();
+    assert_eq!(
+        wrong,
+        Err(NewMatrixError::GenericArgumentMismatch {
+            expected: (3, 2),
+            found: (2, 3)
+        })
+    );
+}
@@ -0,0 +1,59 @@
+use std::error::Error;
+
+use qmat::{
+    errors::{MatrixOperationError, NewMatrixError},
+    prelude::*,
+};
+
+#[test]
+fn question_mark_into_box_dyn_error() {
+    fn invert() -> Result<Matrix<f64, 2, 2, 4>, Box<dyn Error>> {
+        let mat = Matrix::<f64, 2, 2, 4>::from_rows([[1.0, 2.0], [2.0, 4.0]])?;
+        Ok(mat.inverse()?)
+    }
+    let err = invert().unwrap_err();
+    assert_eq!(err.to_string(), "the matrix is singular");
+}
+
+#[test]
+fn new_matrix_error_display() {
+    let err = DMatrix::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+    assert_eq!(err.to_string(), "row 1 has 1 elements, expected 2");
+    assert_eq!(
+        NewMatrixError::GenericArgumentMismatch {
+            expected: (2, 2),
+            found: (3, 1)
+        }
+        .to_string(),
+        "expected a 2x2 matrix, found 3x1"
+    );
+}
+
+#[test]
+fn matrix_operation_error_display() {
+    let cases = [
+        (MatrixOperationError::Singular, "the matrix is singular"),
+        (
+            MatrixOperationError::NotSquare { rows: 2, cols: 3 },
+            "expected a square matrix, found 2x3",
+        ),
+        (
+            MatrixOperationError::NotConverged { iterations: 100 },
+            "didn't converge after 100 iterations",
+        ),
+        (
+            MatrixOperationError::NotPositiveDefinite,
+            "the matrix isn't positive-definite",
+        ),
+        (
+            MatrixOperationError::OutOfBounds {
+                pos: Position(4, 0),
+                dims: (2, 2),
+            },
+            "position (4, 0) is out of bounds for a 2x2 matrix",
+        ),
+    ];
+    for (err, msg) in cases {
+        assert_eq!(err.to_string(), msg);
+    }
+}
@@ -0,0 +1,45 @@
+use qmat::{export::LatexEnvironment, prelude::*};
+
+#[test]
+fn latex() {
+    let mat = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(
+        mat.to_latex(LatexEnvironment::Pmatrix, None),
+        "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6\n\\end{pmatrix}"
+    );
+    let dmat: DMatrix<f64> = row_vector![0.125, 2.0].into();
+    assert_eq!(
+        dmat.to_latex(LatexEnvironment::Bmatrix, Some(3)),
+        "\\begin{bmatrix}\n0.125 & 2.000\n\\end{bmatrix}"
+    );
+}
+
+#[test]
+fn markdown() {
+    let mat = col_vector![1.5, -3.0];
+    assert_eq!(
+        mat.to_markdown(Some(1)),
+        "| 0 |\n| --: |\n| 1.5 |\n| -3.0 |\n"
+    );
+}
+
+#[test]
+fn rust_literal_round_trips() {
+    let mat = matrix![1.0, -0.5; 2.0, 3.25; 0.0, 1e-7];
+    let literal = mat.to_rust_literal(None);
+    assert_eq!(
+        literal,
+        "qmat::matrix!([[1.0, -0.5], [2.0, 3.25], [0.0, 1e-7]])"
+    );
+    assert_eq!(qmat::matrix!([[1.0, -0.5], [2.0, 3.25], [0.0, 1e-7]]), mat);
+
+    let dmat: DMatrix<i32> = matrix![1, -2; 3, 4].into();
+    assert_eq!(
+        dmat.to_rust_literal(None),
+        "qmat::matrix!([[1, -2], [3, 4]])"
+    );
+    assert_eq!(
+        matrix![0.1, 0.25; 1.0, 2.0].to_rust_literal(Some(2)),
+        "qmat::matrix!([[0.10, 0.25], [1.00, 2.00]])"
+    );
+}
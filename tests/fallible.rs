@@ -0,0 +1,99 @@
+use qmat::{
+    errors::{MatrixOperationError, NewMatrixError},
+    prelude::*,
+};
+
+#[test]
+fn get_and_get_mut() {
+    let mut mat = matrix![0, 1, 2; 3, 4, 5];
+    assert_eq!(mat.get(Position(1, 0)), Some(&3));
+    // would be in the flat array, but isn't in the matrix
+    assert_eq!(mat.get(Position(0, 3)), None);
+    assert_eq!(mat.get(Position(2, 0)), None);
+
+    *mat.get_mut(Position(0, 2)).unwrap() = 20;
+    assert_eq!(mat[[0, 2]], 20);
+    assert!(mat.get_mut(Position(5, 5)).is_none());
+}
+
+#[test]
+fn try_get() {
+    let mut mat = matrix![0, 1; 2, 3];
+    assert_eq!(mat.try_get(Position(1, 1)), Ok(&3));
+    assert_eq!(
+        mat.try_get(Position(1, 2)),
+        Err(MatrixOperationError::OutOfBounds {
+            pos: Position(1, 2),
+            dims: (2, 2)
+        })
+    );
+    *mat.try_get_mut(Position(0, 0)).unwrap() = 7;
+    assert_eq!(mat[[0, 0]], 7);
+    assert!(mat.try_get_mut(Position(2, 0)).is_err());
+}
+
+#[test]
+fn try_get_row_and_col() {
+    let mat = matrix![0, 1, 2; 3, 4, 5];
+    assert_eq!(mat.try_get_row(0), Ok(row_vector![0, 1, 2]));
+    assert_eq!(mat.try_get_col(2), Ok(col_vector![2, 5]));
+    assert_eq!(
+        mat.try_get_row(2),
+        Err(MatrixOperationError::OutOfBounds {
+            pos: Position(2, 0),
+            dims: (2, 3)
+        })
+    );
+    assert_eq!(
+        mat.try_get_col(3),
+        Err(MatrixOperationError::OutOfBounds {
+            pos: Position(0, 3),
+            dims: (2, 3)
+        })
+    );
+}
+
+#[test]
+fn try_multiply() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![1, 0; 0, 1; 1, 1];
+    assert_eq!(a.try_multiply::<2, 6, 4>(&b), Ok(matrix![4, 5; 10, 11]));
+    assert_eq!(
+        a.try_multiply::<2, 6, 4>(&b).unwrap(),
+        a.multiply::<2, 6, 4>(&b)
+    );
+    assert_eq!(
+        a.try_multiply::<2, 6, 6>(&b),
+        Err(MatrixOperationError::IllegalOutput(
+            NewMatrixError::IllegalGenerics { m: 2, n: 2, len: 6 }
+        ))
+    );
+}
+
+#[test]
+fn try_outer() {
+    let a = col_vector![1, 2, 3];
+    let b = col_vector![4, 5];
+    assert_eq!(a.try_outer::<2, 6>(&b), Ok(a.outer::<2, 6>(&b)));
+    assert_eq!(
+        a.try_outer::<2, 7>(&b),
+        Err(MatrixOperationError::IllegalOutput(
+            NewMatrixError::IllegalGenerics { m: 3, n: 2, len: 7 }
+        ))
+    );
+}
+
+#[test]
+fn try_diag() {
+    assert_eq!(Matrix::<i32, 2, 2, 4>::try_diag(3), Ok(matrix![3, 0; 0, 3]));
+    assert_eq!(
+        Matrix::<i32, 2, 2, 5>::try_diag(3),
+        Err(NewMatrixError::IllegalGenerics { m: 2, n: 2, len: 5 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "column index out of bounds")]
+fn get_col_out_of_bounds() {
+    let _ = matrix![0, 1; 2, 3].get_col(2);
+}
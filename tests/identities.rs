@@ -57,3 +57,93 @@ mod primitives {
     test_random_gen!(f32);
     test_random_gen!(f64);
 }
+
+#[cfg(test)]
+mod algebra {
+    use std::num::Wrapping;
+
+    use num_complex::Complex;
+    use num_rational::Ratio;
+    use qmat::{
+        identities::{Field, Identity, RealField, Ring, Zero},
+        prelude::*,
+    };
+
+    fn ring_id<T: Ring>() -> (T, T) {
+        (T::zero(), T::identity())
+    }
+
+    fn field_inv<T: Field>(x: T) -> T {
+        T::identity() / x
+    }
+
+    fn real_sqrt<T: RealField>(x: T) -> T {
+        x.sqrt()
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(<i32 as Zero>::zero(), 0);
+        assert_eq!(<f64 as Zero>::zero(), 0.0);
+        assert!(Zero::is_zero(&Wrapping(0u8)));
+        assert!(!Zero::is_zero(&Complex::new(0.0, 1.0)));
+
+        let mat: Matrix<i32, 2, 3, 6> = Zero::zero();
+        assert_eq!(mat, matrix![0, 0, 0; 0, 0, 0]);
+        assert!(Zero::is_zero(&mat));
+        assert!(!Zero::is_zero(&matrix![0, 1; 0, 0]));
+    }
+
+    #[test]
+    fn rings() {
+        assert_eq!(ring_id::<u8>(), (0, 1));
+        assert_eq!(ring_id::<Wrapping<u8>>(), (Wrapping(0), Wrapping(1)));
+        assert_eq!(
+            ring_id::<Complex<i32>>(),
+            (Complex::new(0, 0), Complex::new(1, 0))
+        );
+        assert_eq!(
+            ring_id::<Ratio<i64>>(),
+            (Ratio::from_integer(0), Ratio::from_integer(1))
+        );
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(field_inv(4.0f32), 0.25);
+        assert_eq!(field_inv(Ratio::new(2, 3)), Ratio::new(3, 2));
+        assert_eq!(field_inv(Complex::new(0.0, 2.0)), Complex::new(0.0, -0.5));
+        assert_eq!(real_sqrt(9.0f64), 3.0);
+    }
+
+    #[test]
+    fn identity_matrices() {
+        let id: Matrix<Wrapping<u8>, 2, 2, 4> = Matrix::identity();
+        assert_eq!(id[[0, 0]], Wrapping(1));
+        assert_eq!(id[[0, 1]], Wrapping(0));
+
+        let id: Matrix<Complex<f64>, 2, 2, 4> = Matrix::identity();
+        assert_eq!(id[[1, 1]], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn det_over_other_rings() {
+        let mat = matrix![
+            Ratio::new(1, 2), Ratio::new(1, 3);
+            Ratio::new(1, 4), Ratio::new(1, 5)
+        ];
+        assert_eq!(mat.det(), Ratio::new(1, 60));
+
+        let mat =
+            matrix![Complex::new(0, 1), Complex::new(1, 0); Complex::new(1, 0), Complex::new(0, 1)];
+        assert_eq!(mat.det(), Complex::new(-2, 0));
+
+        let inv = matrix![Ratio::new(1, 2), Ratio::new(0, 1); Ratio::new(0, 1), Ratio::new(4, 1)]
+            .inverse()
+            .unwrap();
+        assert_eq!(
+            inv,
+            matrix![Ratio::new(2, 1), Ratio::new(0, 1); Ratio::new(0, 1), Ratio::new(1, 4)]
+        );
+    }
+}
@@ -6,7 +6,8 @@ fn new_with_illegal_generics() {
     let res = Matrix::<i32, 2, 2, 5>::new([0; 5]);
     assert!(res.is_err());
     if let Err(e) = res {
-        assert_eq!(e, NewMatrixError::IllegalGenerics);
+        assert_eq!(e, NewMatrixError::IllegalGenerics { m: 2, n: 2, len: 5 });
+        assert_eq!(e.to_string(), "LEN must be M * N, but 2 * 2 != 5");
     }
 }
 
@@ -46,6 +47,24 @@ fn get_col() {
     assert_eq!(*mat.get_col(2).as_flat_array(), [2, 5]);
 }
 
+#[test]
+fn get_row_col_orientation() {
+    let mat = matrix!(2, 3, [0, 1, 2, 3, 4, 5]);
+    let row = mat.get_row(1);
+    let col = mat.get_col(2);
+    assert_eq!((row.rows(), row.cols()), (1, 3));
+    assert_eq!((col.rows(), col.cols()), (2, 1));
+    assert_eq!(row[2], 5);
+    assert_eq!(col[0], 2);
+}
+
+#[test]
+fn transpose() {
+    let mat = matrix!(2, 3, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(*mat.transpose().as_flat_array(), [0, 3, 1, 4, 2, 5]);
+    assert_eq!(mat.get_row(0).transpose(), mat.transpose().get_col(0));
+}
+
 #[test]
 fn index_mut() {
     const TIMES: usize = 20;
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod mul {
-    use qmat::matrix;
+    use qmat::{col_vector, matrix};
 
     #[test]
     fn basic_scalar_2x3() {
@@ -28,6 +28,23 @@ mod mul {
             [136, 380, 172, 215, 424, 386, 163, 371, 259]
         );
     }
+
+    #[test]
+    fn basic_mat_2x3_3x1() {
+        let a = matrix![1, 2, 3; 4, 5, 6];
+        let b = col_vector![1, 0, 2];
+        let output = a.multiply::<1, 3, 2>(&b);
+        assert_eq!(*output.as_flat_array(), [7, 16]);
+    }
+
+    #[test]
+    fn more_rows_than_cols() {
+        // looping over `0..N` rows instead of `0..M` used to leave the last row zeroed
+        let a = matrix![1, 2; 3, 4; 5, 6];
+        let b = matrix![1, 0; 0, 2];
+        let output = a.multiply::<2, 4, 6>(&b);
+        assert_eq!(output, matrix![1, 4; 3, 8; 5, 12]);
+    }
 }
 
 #[cfg(test)]
@@ -70,13 +87,110 @@ mod inverse {
         )
     }
 
+    #[test]
+    fn nonsingular_3x3() {
+        let mat = matrix!(3, 3, [0.0, 2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 4.0]);
+        let inv = mat.inverse().unwrap();
+        assert_eq!(
+            *inv.as_flat_array(),
+            [0.0, 1.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.25]
+        );
+    }
+
+    #[test]
+    fn tiny_leading_pivot_3x3() {
+        // dividing by the 1e-20 instead of swapping in the row below loses the top-left element entirely
+        let mat = matrix!(3, 3, [1e-20, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        let inv = mat.inverse().unwrap();
+        let expected: [f64; 9] = [-1.0, 1.0, 0.0, 1.0, -1e-20, 0.0, 0.0, 0.0, 1.0];
+        for (x, y) in inv.as_flat_array().iter().zip(expected) {
+            assert!((x - y).abs() < 1e-12, "{x} != {y}");
+        }
+    }
+
+    #[test]
+    fn singular_3x3() {
+        let mat = matrix!(3, 3, [1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0]);
+        assert_eq!(mat.inverse(), Err(MatrixOperationError::Singular));
+    }
+
     #[test]
     fn singular_2x2() {
-        let mat = matrix!(2, 2, [3, 6, 2, 4]);
+        let mat = matrix!(2, 2, [3.0, 6.0, 2.0, 4.0]);
         let res = mat.inverse();
         assert!(res.is_err());
         if let Err(e) = res {
-            assert_eq!(e, MatrixOperationError::InvalidDeterminant);
+            assert_eq!(e, MatrixOperationError::Singular);
+        }
+    }
+}
+
+#[cfg(test)]
+mod solve {
+    use qmat::{errors::MatrixOperationError, matrix, prelude::*};
+
+    #[test]
+    fn nonsingular_3x3() {
+        let mat = matrix![1e-20, 1.0, 0.0; 1.0, 1.0, 0.0; 0.0, 0.0, 4.0];
+        let x = col_vector![1.0f64, 2.0, -0.5];
+        let b = mat.multiply::<1, 3, 3>(&x);
+        let solved = mat.solve(&b).unwrap();
+        for (found, expected) in solved.as_flat_array().iter().zip(x.as_flat_array()) {
+            assert!((found - expected).abs() < 1e-12, "{found} != {expected}");
+        }
+    }
+
+    #[test]
+    fn singular_3x3() {
+        let mat = matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 0.0, 1.0, 1.0];
+        let b = col_vector![1.0, 2.0, 3.0];
+        assert_eq!(mat.solve(&b), Err(MatrixOperationError::Singular));
+    }
+}
+
+#[cfg(test)]
+mod without_default {
+    use std::ops::{Add, Mul};
+
+    use qmat::prelude::*;
+
+    /// Has no `Default`, so only the algebra traits can build matrices of it.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Num(i32);
+
+    impl Add for Num {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Num(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Num {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Num(self.0 * rhs.0)
+        }
+    }
+
+    impl num_traits::Zero for Num {
+        fn zero() -> Self {
+            Num(0)
         }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    #[test]
+    fn multiply_and_reshape() {
+        let mat = Matrix::<_, 2, 2, 4>::from_rows([[Num(1), Num(2)], [Num(3), Num(4)]]).unwrap();
+        let squared = mat.multiply::<2, 4, 4>(&mat);
+        assert_eq!(
+            *squared.as_flat_array(),
+            [Num(7), Num(10), Num(15), Num(22)]
+        );
+        assert_eq!(mat.transpose().get_row(0), mat.get_col(0).transpose());
+        assert_eq!(mat.into_iter_row().next(), Some(mat.get_row(0)));
+        assert_eq!(mat.into_iter_col().last(), Some(mat.get_col(1)));
     }
 }
@@ -0,0 +1,174 @@
+#![cfg(feature = "matfile")]
+
+use qmat::{
+    errors::MatFileError,
+    matfile::{MatClass, MatFile},
+    prelude::*,
+};
+
+fn header(endian: &[u8; 2]) -> Vec<u8> {
+    let mut header = b"MATLAB 5.0 MAT-file, Platform: GLNXA64".to_vec();
+    header.resize(124, b' ');
+    header.extend(if endian == b"IM" { [0, 1] } else { [1, 0] });
+    header.extend(endian);
+    header
+}
+
+/// An element the way MATLAB writes it, packing small ones into the tag.
+fn element(big_endian: bool, mi_type: u32, data: &[u8]) -> Vec<u8> {
+    let u32_bytes = |x: u32| {
+        if big_endian {
+            x.to_be_bytes()
+        } else {
+            x.to_le_bytes()
+        }
+    };
+    let len = u32::try_from(data.len()).unwrap();
+    let mut out = Vec::new();
+    if data.len() <= 4 {
+        out.extend(u32_bytes((len << 16) | mi_type));
+        out.extend(data);
+        out.resize(8, 0);
+    } else {
+        out.extend(u32_bytes(mi_type));
+        out.extend(u32_bytes(len));
+        out.extend(data);
+        out.resize(8 + data.len().div_ceil(8) * 8, 0);
+    }
+    out
+}
+
+/// A `miMATRIX` of the given class, with `elements` stored as `miUINT8` like MATLAB does for small doubles.
+fn matrix_element(
+    big_endian: bool,
+    class: u8,
+    dims: &[i32],
+    name: &str,
+    elements: &[u8],
+) -> Vec<u8> {
+    let mut flags = vec![0; 8];
+    flags[if big_endian { 3 } else { 0 }] = class;
+    let dims: Vec<u8> = dims
+        .iter()
+        .flat_map(|&dim| {
+            if big_endian {
+                dim.to_be_bytes()
+            } else {
+                dim.to_le_bytes()
+            }
+        })
+        .collect();
+
+    let mut contents = element(big_endian, 6, &flags);
+    contents.extend(element(big_endian, 5, &dims));
+    contents.extend(element(big_endian, 1, name.as_bytes()));
+    contents.extend(element(big_endian, 2, elements));
+    element(big_endian, 14, &contents)
+}
+
+#[test]
+fn round_trip() {
+    for compress in [false, true] {
+        let mut file = MatFile::new();
+        file.insert("A", matrix![1.5, -2.0, 3.0; 4.0, 5.0, 6.25]);
+        file.insert("ids", DMatrix::new(3, 1, vec![7i32, -8, 9]).unwrap());
+        file.insert("x", row_vector![1.0f32, 2.0]);
+
+        let mut buf = Vec::new();
+        file.write(&mut buf, compress).unwrap();
+        assert_eq!(&buf[..19], b"MATLAB 5.0 MAT-file");
+        assert_eq!(&buf[126..128], b"IM");
+
+        let read = MatFile::read(buf.as_slice()).unwrap();
+        assert_eq!(read, file);
+        assert_eq!(read.names().collect::<Vec<_>>(), ["A", "ids", "x"]);
+        assert_eq!(read.class("x"), Some(MatClass::Single));
+        let a: Matrix<f64, 2, 3, 6> = read.get_matrix("A").unwrap();
+        assert_eq!(a, matrix![1.5, -2.0, 3.0; 4.0, 5.0, 6.25]);
+        assert_eq!(read.get::<i32>("ids").unwrap().as_flat_slice(), &[7, -8, 9]);
+    }
+}
+
+#[test]
+fn insert_replaces() {
+    let mut file = MatFile::new();
+    file.insert("A", row_vector![1, 2]);
+    file.insert("A", row_vector![3u8, 4, 5]);
+    assert_eq!(file.names().count(), 1);
+    assert_eq!(file.get::<u8>("A").unwrap().as_flat_slice(), &[3, 4, 5]);
+}
+
+#[test]
+fn reads_matlab_layout() {
+    for (endian, big_endian) in [(b"IM", false), (b"MI", true)] {
+        // a 2x2 double stored column-major as uint8, with a name short enough to be a small element
+        let mut file = header(endian);
+        file.extend(matrix_element(big_endian, 6, &[2, 2], "B", &[1, 3, 2, 4]));
+
+        let read = MatFile::read(file.as_slice()).unwrap();
+        let b: Matrix<f64, 2, 2, 4> = read.get_matrix("B").unwrap();
+        assert_eq!(b, matrix![1.0, 2.0; 3.0, 4.0]);
+    }
+}
+
+#[test]
+fn errors() {
+    let mut file = header(b"IM");
+    file.extend(matrix_element(false, 6, &[1, 2], "C", &[1, 2]));
+    // a cell array
+    file.extend(matrix_element(false, 1, &[1, 1], "cell", &[]));
+    let read = MatFile::read(file.as_slice()).unwrap();
+
+    assert!(matches!(
+        read.get::<f32>("C"),
+        Err(MatFileError::ClassMismatch {
+            expected: MatClass::Single,
+            found: MatClass::Double,
+            ..
+        })
+    ));
+    assert!(matches!(
+        read.get_matrix::<f64, 2, 1, 2>("C"),
+        Err(MatFileError::DimensionMismatch {
+            expected: (2, 1),
+            found: (1, 2)
+        })
+    ));
+    assert!(matches!(
+        read.get::<f64>("cell"),
+        Err(MatFileError::UnsupportedArray(name)) if name == "cell"
+    ));
+    assert!(matches!(
+        read.get::<f64>("D"),
+        Err(MatFileError::NotFound(name)) if name == "D"
+    ));
+
+    assert!(matches!(
+        MatFile::read(&file[..file.len() - 3]),
+        Err(MatFileError::InvalidElement(_))
+    ));
+    assert!(matches!(
+        MatFile::read([0; 128].as_slice()),
+        Err(MatFileError::InvalidHeader)
+    ));
+}
+
+#[test]
+fn oversized_dimensions() {
+    // 4294967295 * 4294967295 doubles overflows a usize
+    let mut contents = element(false, 6, &[6, 0, 0, 0, 0, 0, 0, 0]);
+    contents.extend(element(false, 6, &[[0xff; 4], [0xff; 4]].concat()));
+    contents.extend(element(false, 1, b"E"));
+    contents.extend(element(false, 9, &1.0f64.to_le_bytes()));
+    let mut file = header(b"IM");
+    file.extend(element(false, 14, &contents));
+    assert!(matches!(
+        MatFile::read(file.as_slice()),
+        Err(MatFileError::InvalidElement(_))
+    ));
+
+    let mut file = MatFile::new();
+    file.insert("tall", DMatrix::<f64>::new(1 << 31, 0, vec![]).unwrap());
+    let err = file.write(Vec::new(), false).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,109 @@
+use qmat::{errors::MatrixOperationError, identities::Identity, modular::Gf, prelude::*};
+
+type F7 = Gf<7>;
+
+#[test]
+fn arithmetic() {
+    let (a, b) = (F7::new(5), F7::new(4));
+    assert_eq!(a + b, F7::new(2));
+    assert_eq!(a - b, F7::new(1));
+    assert_eq!(b - a, F7::new(6));
+    assert_eq!(a * b, F7::new(6));
+    assert_eq!(a / b, F7::new(3));
+    assert_eq!(-a, F7::new(2));
+    assert_eq!(-F7::new(0), F7::new(0));
+    assert_eq!(F7::new(30).value(), 2);
+    assert_eq!(F7::from_i64(-1), F7::new(6));
+    assert_eq!(
+        F7::from_i64(i64::MIN),
+        F7::new(7 - (i64::MIN.unsigned_abs() % 7))
+    );
+    assert_eq!(F7::new(3).pow(6), F7::identity());
+    assert_eq!(F7::new(2).to_string(), "2");
+}
+
+#[test]
+fn inverses() {
+    for x in 1..7 {
+        let x = F7::new(x);
+        assert_eq!(x * x.checked_inv().unwrap(), F7::identity());
+    }
+    assert_eq!(F7::new(0).checked_inv(), None);
+}
+
+#[test]
+#[should_panic(expected = "zero has no inverse")]
+fn divide_by_zero() {
+    let _ = F7::new(1) / F7::new(0);
+}
+
+#[test]
+fn large_modulus() {
+    // a Mersenne prime, as used in hashing
+    type F = Gf<{ (1 << 61) - 1 }>;
+    let x = F::new((1 << 61) - 2);
+    assert_eq!(x * x, F::new(1));
+    assert_eq!(x + x, F::new((1 << 61) - 3));
+    assert_eq!(x * x.checked_inv().unwrap(), F::new(1));
+    // the largest prime below 2^64
+    type G = Gf<18_446_744_073_709_551_557>;
+    let y = G::new(u64::MAX);
+    assert_eq!(y + y, G::new(116));
+}
+
+#[test]
+fn det() {
+    let mat =
+        Matrix::<Gf<11>, 3, 3, 9>::new([2, -3, 1, 2, 0, -1, 1, 4, 5].map(Gf::from_i64)).unwrap();
+    // 49 mod 11
+    assert_eq!(mat.det(), Gf::new(5));
+    assert_eq!(matrix![1, 1; 1, 1].map_gf::<2>().det(), Gf::new(0));
+    // singular mod 3, but not over the integers
+    assert_eq!(matrix![1, 2; 2, 1].map_gf::<3>().det(), Gf::new(0));
+}
+
+#[test]
+fn inverse() {
+    let mat = matrix![6, 24, 1; 13, 16, 10; 20, 17, 15].map_gf::<29>();
+    let inv = mat.inverse().unwrap();
+    let id: Matrix<Gf<29>, 3, 3, 9> = Matrix::identity();
+    assert_eq!(mat.multiply::<3, 9, 9>(&inv), id);
+    assert_eq!(inv.multiply::<3, 9, 9>(&mat), id);
+
+    assert_eq!(
+        matrix![1, 2; 2, 1].map_gf::<3>().inverse(),
+        Err(MatrixOperationError::Singular)
+    );
+}
+
+#[test]
+fn rank() {
+    // parity check matrix of the [7, 4] Hamming code
+    let h = matrix![
+        1, 0, 1, 0, 1, 0, 1;
+        0, 1, 1, 0, 0, 1, 1;
+        0, 0, 0, 1, 1, 1, 1
+    ]
+    .map_gf::<2>();
+    assert_eq!(h.rank(), 3);
+    assert_eq!(matrix![1, 2; 2, 4].map_gf::<5>().rank(), 1);
+    assert_eq!(matrix![1, 2; 2, 1].map_gf::<3>().rank(), 1);
+    assert_eq!(matrix![1, 2; 2, 1].map_gf::<5>().rank(), 2);
+    assert_eq!(matrix![0, 0; 0, 0].map_gf::<5>().rank(), 0);
+}
+
+#[test]
+fn solve() {
+    let mat = matrix![0, 1, 1; 1, 0, 1; 1, 1, 0].map_gf::<13>();
+    let x = col_vector![3, 7, 12].map_gf::<13>();
+    let b = mat.multiply::<1, 3, 3>(&x);
+    assert_eq!(mat.solve(&b), Ok(x));
+
+    // over GF(2), the same matrix is singular
+    assert_eq!(
+        matrix![0, 1, 1; 1, 0, 1; 1, 1, 0]
+            .map_gf::<2>()
+            .solve(&col_vector![1, 0, 0].map_gf::<2>()),
+        Err(MatrixOperationError::Singular)
+    );
+}
@@ -0,0 +1,209 @@
+use num_complex::Complex;
+use qmat::{errors::MtxError, mtx::MtxField, prelude::*, sparse::CooMatrix};
+
+fn to_string<F: FnOnce(&mut Vec<u8>)>(write: F) -> String {
+    let mut buf = Vec::new();
+    write(&mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn dense_round_trip() {
+    let mat = matrix![1.5, -2.0; 3.25, 4.0; 0.0, 1e-3];
+    let mtx = to_string(|buf| mat.to_mtx(buf).unwrap());
+    assert_eq!(
+        mtx,
+        "%%MatrixMarket matrix array real general\n3 2\n1.5\n3.25\n0.0\n-2.0\n4.0\n0.001\n"
+    );
+    assert_eq!(
+        Matrix::<f64, 3, 2, 6>::from_mtx(mtx.as_bytes()).unwrap(),
+        mat
+    );
+    let dmat = DMatrix::<f64>::from_mtx(mtx.as_bytes()).unwrap();
+    assert_eq!(dmat, mat.into());
+}
+
+#[test]
+fn sparse_round_trip() {
+    let mut sparse = CooMatrix::new(3, 4);
+    sparse.push(Position(0, 3), 7);
+    sparse.push(Position(2, 1), -2);
+    let mtx = to_string(|buf| sparse.to_mtx(buf).unwrap());
+    assert_eq!(
+        mtx,
+        "%%MatrixMarket matrix coordinate integer general\n3 4 2\n1 4 7\n3 2 -2\n"
+    );
+    assert_eq!(CooMatrix::<i32>::from_mtx(mtx.as_bytes()).unwrap(), sparse);
+    assert_eq!(
+        Matrix::<i32, 3, 4, 12>::from_mtx(mtx.as_bytes()).unwrap(),
+        matrix![0, 0, 0, 7; 0, 0, 0, 0; 0, -2, 0, 0]
+    );
+}
+
+#[test]
+fn complex_round_trip() {
+    let mat = row_vector![Complex::new(1.0, -1.0), Complex::new(0.0, 2.5)];
+    let mtx = to_string(|buf| mat.to_mtx(buf).unwrap());
+    assert_eq!(
+        mtx,
+        "%%MatrixMarket matrix array complex general\n1 2\n1.0 -1.0\n0.0 2.5\n"
+    );
+    assert_eq!(
+        Matrix::<Complex<f64>, 1, 2, 2>::from_mtx(mtx.as_bytes()).unwrap(),
+        mat
+    );
+}
+
+#[test]
+fn symmetric_array() {
+    let mtx = "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+    assert_eq!(
+        Matrix::<i64, 3, 3, 9>::from_mtx(mtx.as_bytes()).unwrap(),
+        matrix![1, 2, 3; 2, 4, 5; 3, 5, 6]
+    );
+}
+
+#[test]
+fn skew_symmetric() {
+    let mtx = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+    assert_eq!(
+        Matrix::<f32, 3, 3, 9>::from_mtx(mtx.as_bytes()).unwrap(),
+        matrix![0.0, -1.0, -2.0; 1.0, 0.0, -3.0; 2.0, 3.0, 0.0]
+    );
+
+    let mtx = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 4\n";
+    assert!(matches!(
+        Matrix::<u8, 2, 2, 4>::from_mtx(mtx.as_bytes()),
+        Err(MtxError::InvalidEntry { line: 3, .. })
+    ));
+}
+
+#[test]
+fn hermitian() {
+    let mtx = "%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n1 1 3 0\n2 1 1 2\n";
+    assert_eq!(
+        Matrix::<Complex<f64>, 2, 2, 4>::from_mtx(mtx.as_bytes()).unwrap(),
+        matrix![
+            Complex::new(3.0, 0.0), Complex::new(1.0, -2.0);
+            Complex::new(1.0, 2.0), Complex::new(0.0, 0.0)
+        ]
+    );
+}
+
+#[test]
+fn pattern_and_comments() {
+    let mtx = "%%MatrixMarket MATRIX Coordinate Pattern General\n% comment\n\n2 2 2\n% another\n1 2\n2 1\n";
+    assert_eq!(
+        Matrix::<u8, 2, 2, 4>::from_mtx(mtx.as_bytes()).unwrap(),
+        matrix![0, 1; 1, 0]
+    );
+}
+
+#[test]
+fn repeated_entries_are_summed() {
+    let mtx = "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 2 1.5\n2 1 4.0\n1 2 2.0\n";
+    let expected = matrix![0.0, 3.5; 4.0, 0.0];
+    assert_eq!(
+        Matrix::<f64, 2, 2, 4>::from_mtx(mtx.as_bytes()).unwrap(),
+        expected
+    );
+    assert_eq!(
+        DMatrix::<f64>::from_mtx(mtx.as_bytes()).unwrap(),
+        expected.into()
+    );
+    assert_eq!(
+        CooMatrix::<f64>::from_mtx(mtx.as_bytes())
+            .unwrap()
+            .to_dense(),
+        expected.into()
+    );
+}
+
+#[test]
+fn errors() {
+    let bad_header = "%%MatrixMarket matrix banana real general\n1 1\n1\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(bad_header.as_bytes()),
+        Err(MtxError::InvalidHeader { line: 1, .. })
+    ));
+
+    let complex = "%%MatrixMarket matrix array complex general\n1 1\n1 2\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(complex.as_bytes()),
+        Err(MtxError::IncompatibleField(MtxField::Complex))
+    ));
+
+    let out_of_bounds = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(out_of_bounds.as_bytes()),
+        Err(MtxError::EntryOutOfBounds {
+            line: 3,
+            pos: Position(2, 0)
+        })
+    ));
+
+    let too_few = "%%MatrixMarket matrix array real general\n2 1\n1.0\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(too_few.as_bytes()),
+        Err(MtxError::WrongEntryCount {
+            expected: 2,
+            found: 1
+        })
+    ));
+
+    let wrong_dims = "%%MatrixMarket matrix array real general\n2 1\n1.0\n2.0\n";
+    assert!(matches!(
+        Matrix::<f64, 1, 2, 2>::from_mtx(wrong_dims.as_bytes()),
+        Err(MtxError::DimensionMismatch {
+            expected: (1, 2),
+            found: (2, 1)
+        })
+    ));
+}
+
+#[test]
+fn hostile_headers() {
+    // a huge entry count mustn't be allocated up front
+    let huge_nnz =
+        "%%MatrixMarket matrix coordinate real general\n1 1 18446744073709551615\n1 1 1.0\n";
+    assert!(matches!(
+        CooMatrix::<f64>::from_mtx(huge_nnz.as_bytes()),
+        Err(MtxError::WrongEntryCount { found: 1, .. })
+    ));
+
+    let overflowing_array = "%%MatrixMarket matrix array real general\n4294967296 4294967296\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(overflowing_array.as_bytes()),
+        Err(MtxError::InvalidHeader { line: 2, .. })
+    ));
+    let overflowing_symmetric =
+        "%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(overflowing_symmetric.as_bytes()),
+        Err(MtxError::InvalidHeader { line: 2, .. })
+    ));
+
+    // fine as a sparse matrix, but far too big to be dense
+    let huge_coordinate =
+        "%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 0\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(huge_coordinate.as_bytes()),
+        Err(MtxError::InvalidHeader { line: 2, .. })
+    ));
+    // the header is checked against the static shape before anything is allocated
+    assert!(matches!(
+        Matrix::<f64, 2, 2, 4>::from_mtx(huge_coordinate.as_bytes()),
+        Err(MtxError::DimensionMismatch {
+            expected: (2, 2),
+            found: (4294967296, 4294967296)
+        })
+    ));
+    let sparse = CooMatrix::<f64>::from_mtx(huge_coordinate.as_bytes()).unwrap();
+    assert_eq!((sparse.rows(), sparse.cols()), (4294967296, 4294967296));
+
+    let unallocatable = "%%MatrixMarket matrix coordinate real general\n1 18446744073709551615 0\n";
+    assert!(matches!(
+        DMatrix::<f64>::from_mtx(unallocatable.as_bytes()),
+        Err(MtxError::InvalidHeader { line: 2, .. })
+    ));
+}
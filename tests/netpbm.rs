@@ -0,0 +1,132 @@
+use qmat::{errors::NetpbmError, netpbm::NetpbmEncoding, prelude::*};
+
+fn write<F: FnOnce(&mut Vec<u8>)>(write: F) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write(&mut buf);
+    buf
+}
+
+#[test]
+fn pgm_binary_round_trip() {
+    let grid = matrix![0u8, 50, 100; 150, 200, 255];
+    let file = write(|buf| grid.to_pgm(buf, NetpbmEncoding::Binary).unwrap());
+    assert_eq!(file, b"P5\n3 2\n255\n\x00\x32\x64\x96\xc8\xff");
+    assert_eq!(
+        Matrix::<u8, 2, 3, 6>::from_pgm(file.as_slice()).unwrap(),
+        grid
+    );
+
+    let heights = matrix![0u16, 1000; 65535, 258];
+    let file = write(|buf| heights.to_pgm(buf, NetpbmEncoding::Binary).unwrap());
+    assert_eq!(&file[..15], b"P5\n2 2\n65535\n\x00\x00");
+    assert_eq!(
+        DMatrix::<u16>::from_pgm(file.as_slice()).unwrap(),
+        heights.into()
+    );
+}
+
+#[test]
+fn ppm_round_trip() {
+    let image = matrix![[255u8, 0, 0], [0, 255, 0]; [0, 0, 255], [10, 20, 30]];
+    for encoding in [NetpbmEncoding::Ascii, NetpbmEncoding::Binary] {
+        let file = write(|buf| image.to_ppm(buf, encoding).unwrap());
+        assert_eq!(
+            Matrix::<[u8; 3], 2, 2, 4>::from_ppm(file.as_slice()).unwrap(),
+            image
+        );
+    }
+
+    let file = write(|buf| image.to_ppm(buf, NetpbmEncoding::Ascii).unwrap());
+    assert_eq!(
+        String::from_utf8(file).unwrap(),
+        "P3\n2 2\n255\n255 0 0 0 255 0\n0 0 255 10 20 30\n"
+    );
+}
+
+#[test]
+fn reads_comments_and_small_maxval() {
+    let file = b"P2\n# a comment\n3 1 # width and height\n15\n0 7\n15\n";
+    let grid = DMatrix::<u8>::from_pgm(&file[..]).unwrap();
+    // scaled from 0..=15 to 0..=255
+    assert_eq!(grid.as_flat_slice(), &[0, 119, 255]);
+    assert_eq!(grid[Position(0, 2)], 255);
+}
+
+#[test]
+fn scales_ten_bit_samples() {
+    let file = b"P5\n3 1\n1023\n\x00\x00\x02\x00\x03\xff";
+    let grid = DMatrix::<u16>::from_pgm(&file[..]).unwrap();
+    // 512 * 65535 / 1023 rounds to 32800
+    assert_eq!(grid.as_flat_slice(), &[0, 32800, 65535]);
+    assert_eq!(grid.dequantize::<f64>()[Position(0, 2)], 1.0);
+}
+
+#[test]
+fn hostile_dimensions() {
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P5\n4294967296 4294967296\n255\n\x00"[..]),
+        Err(NetpbmError::InvalidHeader(_))
+    ));
+    // fits in a usize, but there's nowhere near that much data
+    assert!(matches!(
+        DMatrix::<[u16; 3]>::from_ppm(&b"P3\n1000000000 1000000\n65535\n1 2 3\n"[..]),
+        Err(NetpbmError::Truncated)
+    ));
+    assert!(matches!(
+        DMatrix::<u16>::from_pgm(&b"P5\n3 1\n1023\n\x00\x00\x02\x00\x03"[..]),
+        Err(NetpbmError::Truncated)
+    ));
+}
+
+#[test]
+fn quantize_and_dequantize() {
+    let heights = matrix![-1.0, 0.0; 1.0, 3.0];
+    assert_eq!(heights.quantize::<u8>(None), matrix![0, 64; 128, 255]);
+    assert_eq!(
+        heights.quantize::<u8>(Some((0.0, 1.0))),
+        matrix![0, 0; 255, 255]
+    );
+    assert_eq!(
+        matrix![2.0, 2.0; 2.0, f64::NAN].quantize::<u16>(None),
+        matrix![0, 0; 0, 0]
+    );
+
+    let gray = matrix![0u8, 51; 255, 102];
+    assert_eq!(gray.dequantize::<f32>(), matrix![0.0, 0.2; 1.0, 0.4]);
+    let dgray: DMatrix<u8> = gray.into();
+    assert_eq!(
+        dgray.dequantize::<f32>().quantize::<u8>(Some((0.0, 1.0))),
+        dgray
+    );
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P6\n1 1\n255\nabc"[..]),
+        Err(NetpbmError::WrongFormat { found, .. }) if found == "P6"
+    ));
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P2\n1\n"[..]),
+        Err(NetpbmError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P5\n1 1\n1023\n\x00\x01"[..]),
+        Err(NetpbmError::MaxvalTooLarge(1023))
+    ));
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P2\n2 1\n10\n3 11\n"[..]),
+        Err(NetpbmError::InvalidSample { index: 1 })
+    ));
+    assert!(matches!(
+        DMatrix::<u8>::from_pgm(&b"P5\n2 2\n255\n\x00\x01\x02"[..]),
+        Err(NetpbmError::Truncated)
+    ));
+    assert!(matches!(
+        Matrix::<u8, 2, 1, 2>::from_pgm(&b"P2\n2 1\n255\n1 2\n"[..]),
+        Err(NetpbmError::DimensionMismatch {
+            expected: (2, 1),
+            found: (1, 2)
+        })
+    ));
+}
@@ -36,6 +36,78 @@ mod matrix {
         assert_eq!(mat[[1, 1]], 4);
         assert_eq!(mat[[1, 2]], 5);
     }
+
+    #[test]
+    fn tokentree_runtime_elements() {
+        let (a, b) = (4.5, -1.0);
+        let mat = qmat::matrix!([[a, b], [b * 2.0, a + 1.0],]);
+        assert_eq!(*mat.as_flat_array(), [4.5, -1.0, -2.0, 5.5]);
+    }
+
+    #[test]
+    fn tokentree_single_col() {
+        let mat = qmat::matrix!([[0], [1], [2]]);
+        assert_eq!(mat.rows(), 3);
+        assert_eq!(mat.cols(), 1);
+    }
+
+    #[test]
+    fn semicolon_rows() {
+        let x = 7;
+        let mat = qmat::matrix![1, 2, 3; 4, 5, x];
+        assert_eq!(mat.rows(), 2);
+        assert_eq!(mat.cols(), 3);
+        assert_eq!(*mat.as_flat_array(), [1, 2, 3, 4, 5, 7]);
+    }
+
+    #[test]
+    fn semicolon_trailing() {
+        let mat = qmat::matrix![1, 2; 3, 4;];
+        assert_eq!(*mat.as_flat_array(), [1, 2, 3, 4]);
+        let single_row = qmat::matrix![1, 2, 3;];
+        assert_eq!(single_row.rows(), 1);
+        assert_eq!(single_row.cols(), 3);
+    }
+
+    #[test]
+    fn semicolon_single_col() {
+        let mat = qmat::matrix![1; 2; 3];
+        assert_eq!(mat.rows(), 3);
+        assert_eq!(mat.cols(), 1);
+    }
+
+    #[test]
+    fn rows_variable() {
+        let rows = [[0, 1], [2, 3], [4, 5]];
+        let mat: qmat::mat::Matrix<_, 3, 2, 6> = qmat::matrix!(rows);
+        assert_eq!(*mat.as_flat_array(), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rows_variable_inferred() {
+        let (a, b) = (1.5, -2.0);
+        let rows = [[a, b], [b, a], [0.0, 1.0]];
+        let mat = qmat::matrix!(rows);
+        assert_eq!(mat.rows(), 3);
+        assert_eq!(mat.cols(), 2);
+        assert_eq!(*mat.as_flat_array(), [a, b, b, a, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn rows_const_item() {
+        const ROWS: [[i32; 2]; 2] = [[0, 1], [2, 3]];
+        let mat = qmat::matrix!(ROWS);
+        assert_eq!(*mat.as_flat_array(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn rows_const_item_max_size() {
+        const ROWS: [[u8; 16]; 16] = [[7; 16]; 16];
+        let mat = qmat::matrix!(ROWS);
+        assert_eq!(mat.rows(), 16);
+        assert_eq!(mat.cols(), 16);
+        assert_eq!(mat[[15, 15]], 7);
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +160,25 @@ mod identity {
     test_identities_up_to_5!(f64, 1.0, 0.0);
 }
 
+#[cfg(test)]
+mod vectors {
+    #[test]
+    fn row_vector() {
+        let vec = qmat::row_vector![0.5, 1.5, 2.5];
+        assert_eq!(vec.rows(), 1);
+        assert_eq!(vec.cols(), 3);
+        assert_eq!(*vec.as_flat_array(), [0.5, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn col_vector() {
+        let vec = qmat::col_vector![0, 1, 2, 3];
+        assert_eq!(vec.rows(), 4);
+        assert_eq!(vec.cols(), 1);
+        assert_eq!(*vec.as_flat_array(), [0, 1, 2, 3]);
+    }
+}
+
 #[test]
 fn empty() {
     let mat = qmat::empty!(4, 3, i8).unwrap();
@@ -0,0 +1,45 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use qmat::prelude::*;
+
+#[test]
+fn from_flat() {
+    let mat: Mat<i32, 2, 3> = Mat::from_flat([0, 1, 2, 3, 4, 5]);
+    assert_eq!(mat.rows(), 2);
+    assert_eq!(mat.cols(), 3);
+    assert_eq!(mat[[1, 0]], 3);
+}
+
+#[test]
+fn matmul_non_square() {
+    let a: Mat<i32, 2, 3> = Mat::from_nested([[1, 2, 3], [4, 5, 6]]);
+    let b: Mat<i32, 3, 1> = Mat::from_flat([1, 0, 2]);
+    let output = a.matmul(&b);
+    assert_eq!(*output.as_flat_array(), [7, 16]);
+}
+
+#[test]
+fn same_type_as_matrix() {
+    let a: Mat<i32, 2, 2> = Mat::from_flat([3, 7, 4, 9]);
+    let b: Matrix<i32, 2, 2, 4> = a;
+    assert_eq!(a, b);
+}
+
+fn generic_vol<T, const M: usize, const N: usize, const LEN: usize>(
+    mat: Matrix<T, M, N, LEN>,
+) -> Matrix<T, M, N, LEN>
+where
+    [(); M * N]:,
+{
+    let mat: Mat<T, M, N> = mat.into_mat();
+    mat.into_len().unwrap()
+}
+
+#[test]
+fn conversions() {
+    let mat = matrix!(2, 3, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(generic_vol(mat), mat);
+    assert!(Mat::<i32, 2, 2>::from_flat([0; 4]).into_len::<5>().is_err());
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "npy")]
+
+use qmat::{errors::NpyError, prelude::*};
+
+/// Builds a file the same way numpy does, with the header padded to 64 bytes.
+fn npy_file(version: u8, header: &str, data: &[u8]) -> Vec<u8> {
+    let preamble = if version == 1 { 10 } else { 12 };
+    let mut header = header.to_string();
+    while !(preamble + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut file = b"\x93NUMPY".to_vec();
+    file.extend([version, 0]);
+    if version == 1 {
+        file.extend(u16::try_from(header.len()).unwrap().to_le_bytes());
+    } else {
+        file.extend(u32::try_from(header.len()).unwrap().to_le_bytes());
+    }
+    file.extend(header.as_bytes());
+    file.extend(data);
+    file
+}
+
+#[test]
+fn writes_numpy_header() {
+    let mat = matrix![1.5, -2.0; 3.25, 4.0];
+    let mut buf = Vec::new();
+    mat.to_npy(&mut buf).unwrap();
+
+    let data: Vec<u8> = [1.5f64, -2.0, 3.25, 4.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    let expected = npy_file(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }",
+        &data,
+    );
+    assert_eq!(buf, expected);
+    assert_eq!((buf.len() - data.len()) % 64, 0);
+}
+
+#[test]
+fn round_trip() {
+    let mat = matrix![1u8, 2, 3; 4, 5, 6];
+    let mut buf = Vec::new();
+    mat.to_npy(&mut buf).unwrap();
+    assert_eq!(
+        Matrix::<u8, 2, 3, 6>::from_npy(buf.as_slice()).unwrap(),
+        mat
+    );
+
+    let dmat: DMatrix<i64> = matrix![-1, 2; 3, -4; 5, 6].into();
+    let mut buf = Vec::new();
+    dmat.to_npy(&mut buf).unwrap();
+    assert_eq!(DMatrix::<i64>::from_npy(buf.as_slice()).unwrap(), dmat);
+}
+
+#[test]
+fn fortran_order_and_big_endian() {
+    // [[1, 2, 3], [4, 5, 6]] stored column-major
+    let data: Vec<u8> = [1i32, 4, 2, 5, 3, 6]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let file = npy_file(
+        1,
+        "{'descr': '>i4', 'fortran_order': True, 'shape': (2, 3), }",
+        &data,
+    );
+    assert_eq!(
+        Matrix::<i32, 2, 3, 6>::from_npy(file.as_slice()).unwrap(),
+        matrix![1, 2, 3; 4, 5, 6]
+    );
+}
+
+#[test]
+fn one_dimensional() {
+    let data: Vec<u8> = [1.0f32, 2.0, 3.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    let file = npy_file(
+        2,
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }",
+        &data,
+    );
+
+    assert_eq!(
+        Matrix::<f32, 3, 1, 3>::from_npy(file.as_slice()).unwrap(),
+        col_vector![1.0, 2.0, 3.0]
+    );
+    assert_eq!(
+        Matrix::<f32, 1, 3, 3>::from_npy(file.as_slice()).unwrap(),
+        row_vector![1.0, 2.0, 3.0]
+    );
+    let dmat = DMatrix::<f32>::from_npy(file.as_slice()).unwrap();
+    assert_eq!((dmat.rows(), dmat.cols()), (3, 1));
+}
+
+#[test]
+fn errors() {
+    let data = [0u8; 8];
+    let file = npy_file(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1), }",
+        &data,
+    );
+    assert!(matches!(
+        DMatrix::<f32>::from_npy(file.as_slice()),
+        Err(NpyError::DtypeMismatch { expected, found }) if expected == "<f4" && found == "<f8"
+    ));
+    assert!(matches!(
+        Matrix::<f64, 2, 1, 2>::from_npy(file.as_slice()),
+        Err(NpyError::DimensionMismatch {
+            expected: (2, 1),
+            found: (1, 1)
+        })
+    ));
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(&file[..file.len() - 1]),
+        Err(NpyError::Io(_))
+    ));
+
+    let file = npy_file(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1, 1), }",
+        &data,
+    );
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(file.as_slice()),
+        Err(NpyError::UnsupportedShape(shape)) if shape == [1, 1, 1]
+    ));
+
+    let file = npy_file(1, "{'descr': '<f8', 'shape': (1, 1), }", &data);
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(file.as_slice()),
+        Err(NpyError::InvalidHeader(_))
+    ));
+
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(&b"PK\x03\x04 not numpy"[..]),
+        Err(NpyError::InvalidMagic)
+    ));
+}
+
+#[test]
+fn hostile_shapes() {
+    let file = npy_file(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }",
+        &[],
+    );
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(file.as_slice()),
+        Err(NpyError::InvalidHeader(_))
+    ));
+
+    // fits in a usize, but there's no data to back it
+    let file = npy_file(
+        1,
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1000000000, 1000), }",
+        &[0; 8],
+    );
+    assert!(matches!(
+        DMatrix::<f64>::from_npy(file.as_slice()),
+        Err(NpyError::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
@@ -0,0 +1,106 @@
+use qmat::prelude::*;
+
+#[test]
+fn checked_elementwise() {
+    let a = matrix![1u8, 2; 3, 4];
+    let max = matrix![u8::MAX, 0; 0, 0];
+    assert_eq!(a.checked_add(&a), Some(matrix![2, 4; 6, 8]));
+    assert_eq!(a.checked_add(&max), None);
+    assert_eq!(a.checked_sub(&a), Some(matrix![0, 0; 0, 0]));
+    assert_eq!(a.checked_sub(&max), None);
+    assert_eq!(a.checked_mul_scalar(50), Some(matrix![50, 100; 150, 200]));
+    assert_eq!(a.checked_mul_scalar(100), None);
+}
+
+#[test]
+fn wrapping_elementwise() {
+    let a = matrix![200u8, 1; 2, 3];
+    assert_eq!(a.wrapping_add(&a), matrix![144, 2; 4, 6]);
+    assert_eq!(
+        matrix![0u8, 0; 0, 0].wrapping_sub(&a),
+        matrix![56, 255; 254, 253]
+    );
+    assert_eq!(a.wrapping_mul_scalar(2), matrix![144, 2; 4, 6]);
+}
+
+#[test]
+fn saturating_elementwise() {
+    let a = matrix![200u8, 1; 2, 3];
+    assert_eq!(a.saturating_add(&a), matrix![255, 2; 4, 6]);
+    assert_eq!(
+        matrix![0u8, 0; 0, 0].saturating_sub(&a),
+        matrix![0, 0; 0, 0]
+    );
+    assert_eq!(
+        matrix![100i8, -100; 1, 2].saturating_mul_scalar(2),
+        matrix![127, -128; 2, 4]
+    );
+}
+
+#[test]
+fn products() {
+    let a = matrix![1i16, 2, 3; 4, 5, 6];
+    let b = matrix![7i16, 8; 9, 10; 11, 12];
+    let expected = a.multiply::<2, 6, 4>(&b);
+    assert_eq!(a.checked_mul::<2, 6, 4>(&b), Some(expected));
+    assert_eq!(a.wrapping_mul::<2, 6, 4>(&b), expected);
+
+    let big = a.mul_scalar(100);
+    assert_eq!(big.checked_mul::<2, 6, 4>(&b.mul_scalar(100)), None);
+    // the wrapped product agrees with the exact one modulo 2^16
+    let exact = big.into_iter().map(i64::from).collect::<Vec<_>>();
+    let wrapped = big.wrapping_mul::<2, 6, 4>(&b.mul_scalar(100));
+    let b_big: Vec<i64> = b.mul_scalar(100).into_iter().map(i64::from).collect();
+    for (i, elem) in wrapped.into_iter().enumerate() {
+        let (row, col) = (i / 2, i % 2);
+        let sum: i64 = (0..3)
+            .map(|j| exact[row * 3 + j] * b_big[j * 2 + col])
+            .sum();
+        assert_eq!(i64::from(elem), i64::from(sum as i16));
+    }
+}
+
+#[test]
+fn dots() {
+    let a = col_vector![100u8, 1];
+    assert_eq!(a.checked_dot(&col_vector![2, 3]), Some(203));
+    assert_eq!(a.checked_dot(&col_vector![3, 3]), None);
+    assert_eq!(a.wrapping_dot(&col_vector![3, 3]), 47);
+}
+
+#[test]
+fn checked_det() {
+    assert_eq!(matrix![3i32, 7; 1, -4].checked_det(), Some(-19));
+    assert_eq!(matrix![3i32, 6; 2, 4].checked_det(), Some(0));
+    assert_eq!(
+        matrix![2i32, -3, 1; 2, 0, -1; 1, 4, 5].checked_det(),
+        Some(49)
+    );
+    // needs a row swap
+    assert_eq!(
+        matrix![0i32, 3, 2; -3, -1, -3; 2, 3, 1].checked_det(),
+        Some(-23)
+    );
+    assert_eq!(
+        Matrix::<i32, 1, 1, 1>::new([5]).unwrap().checked_det(),
+        Some(5)
+    );
+    assert_eq!(matrix![i64::MAX, 1; 1, i64::MAX].checked_det(), None);
+    // the determinant fits, but the product before Bareiss' division doesn't
+    assert_eq!(matrix![65536i32, 65536; 65536, 65537].checked_det(), None);
+    assert_eq!(
+        matrix![65536i64, 65536; 65536, 65537].checked_det(),
+        Some(65536)
+    );
+    // stays exact where `det`'s intermediate values would overflow
+    let mat = matrix![
+        1000i32, 999, 998, 997;
+        999, 1000, 999, 998;
+        998, 999, 1000, 999;
+        997, 998, 999, 1000
+    ];
+    let float = Matrix::<f64, 4, 4, 16>::new(mat.into_flat_array().map(f64::from)).unwrap();
+    #[allow(clippy::cast_possible_truncation)]
+    let exact = float.det().round() as i32;
+    assert_eq!(mat.checked_det(), Some(exact));
+}
@@ -0,0 +1,90 @@
+use num_rational::Ratio;
+use qmat::{errors::MatrixOperationError, identities::Identity, prelude::*};
+
+#[test]
+fn to_rational() {
+    let mat = matrix![1i64, -2; 3, 0].to_rational();
+    assert_eq!(
+        mat,
+        matrix![
+            Ratio::from_integer(1), Ratio::from_integer(-2);
+            Ratio::from_integer(3), Ratio::from_integer(0)
+        ]
+    );
+}
+
+#[test]
+fn inverse_exact_2x2() {
+    let mat = matrix![3, 7; 1, -4];
+    let inv = mat.inverse_exact().unwrap();
+    assert_eq!(
+        inv,
+        matrix![
+            Ratio::new(4, 19), Ratio::new(7, 19);
+            Ratio::new(1, 19), Ratio::new(-3, 19)
+        ]
+    );
+}
+
+#[test]
+fn inverse_exact_3x3() {
+    let mat = matrix![2, -3, 1; 2, 0, -1; 1, 4, 5];
+    let inv = mat.inverse_exact().unwrap();
+    let id: Matrix<Ratio<i32>, 3, 3, 9> = Matrix::identity();
+    assert_eq!(mat.to_rational().multiply::<3, 9, 9>(&inv), id);
+    assert_eq!(inv.multiply::<3, 9, 9>(&mat.to_rational()), id);
+    // every element is a cofactor over det(mat) = 49
+    assert!(inv.iter().all(|x| 49 % x.denom() == 0));
+    assert_eq!(inv[[0, 0]], Ratio::new(4, 49));
+}
+
+#[test]
+fn inverse_exact_integral_3x3() {
+    let mat = matrix![2, 1, 0; 1, 1, 0; 0, 0, 1];
+    assert_eq!(
+        mat.inverse_exact(),
+        Ok(matrix![1, -1, 0; -1, 2, 0; 0, 0, 1].to_rational())
+    );
+}
+
+#[test]
+fn inverse_exact_singular() {
+    assert_eq!(
+        matrix![1, 2, 3; 2, 4, 6; 0, 1, 1].inverse_exact(),
+        Err(MatrixOperationError::Singular)
+    );
+    assert_eq!(
+        matrix![3, 6; 2, 4].inverse_exact(),
+        Err(MatrixOperationError::Singular)
+    );
+}
+
+#[test]
+fn rref_exact() {
+    let mat = matrix![
+        0, 2, 4, 2;
+        3, 3, 0, 6;
+        1, 2, 2, 3
+    ];
+    assert_eq!(
+        mat.rref_exact(),
+        matrix![
+            1, 0, -2, 1;
+            0, 1, 2, 1;
+            0, 0, 0, 0
+        ]
+        .to_rational()
+    );
+
+    let mat = matrix![2, 1; 4, 3; 6, 5];
+    assert_eq!(mat.rref_exact(), matrix![1, 0; 0, 1; 0, 0].to_rational());
+
+    let mat = matrix![3, 1, 2; 6, 2, 5];
+    assert_eq!(
+        mat.rref_exact(),
+        matrix![
+            Ratio::from_integer(1), Ratio::new(1, 3), Ratio::from_integer(0);
+            Ratio::from_integer(0), Ratio::from_integer(0), Ratio::from_integer(1)
+        ]
+    );
+}
@@ -19,6 +19,14 @@ fn new_matrix_3() {
     let mat = matrix!([[0, 1, 2], [3, 4, 5]]);
 }
 
+#[test]
+fn new_matrix_4() {
+    use qmat::prelude::*;
+    let mat = matrix![0, 1, 2; 3, 4, 5];
+    let row = row_vector![0, 1, 2];
+    let col = col_vector![0, 3];
+}
+
 #[test]
 fn indexing_usize_2() {
     use qmat::prelude::*;
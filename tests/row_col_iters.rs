@@ -7,15 +7,15 @@ fn into_iter_row() {
 
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 3, 1, 3>::new([0, 1, 2]).unwrap())
+        Some(RowVector::<i32, 3>::new([0, 1, 2]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 3, 1, 3>::new([3, 4, 5]).unwrap())
+        Some(RowVector::<i32, 3>::new([3, 4, 5]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 3, 1, 3>::new([6, 7, 8]).unwrap())
+        Some(RowVector::<i32, 3>::new([6, 7, 8]).unwrap())
     );
 
     assert_eq!(iter.next(), None);
@@ -32,15 +32,15 @@ fn iter_row() {
 
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 3, 1, 3>::new([&0, &1, &2]).unwrap())
+        Some(RowVector::<&i32, 3>::new([&0, &1, &2]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 3, 1, 3>::new([&3, &4, &5]).unwrap())
+        Some(RowVector::<&i32, 3>::new([&3, &4, &5]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 3, 1, 3>::new([&6, &7, &8]).unwrap())
+        Some(RowVector::<&i32, 3>::new([&6, &7, &8]).unwrap())
     );
 
     assert_eq!(iter.next(), None);
@@ -56,15 +56,15 @@ fn into_iter_col() {
 
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 1, 3, 3>::new([0, 3, 6]).unwrap())
+        Some(ColVector::<i32, 3>::new([0, 3, 6]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 1, 3, 3>::new([1, 4, 7]).unwrap())
+        Some(ColVector::<i32, 3>::new([1, 4, 7]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<i32, 1, 3, 3>::new([2, 5, 8]).unwrap())
+        Some(ColVector::<i32, 3>::new([2, 5, 8]).unwrap())
     );
 
     assert_eq!(iter.next(), None);
@@ -81,15 +81,15 @@ fn iter_col() {
 
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 1, 3, 3>::new([&0, &3, &6]).unwrap())
+        Some(ColVector::<&i32, 3>::new([&0, &3, &6]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 1, 3, 3>::new([&1, &4, &7]).unwrap())
+        Some(ColVector::<&i32, 3>::new([&1, &4, &7]).unwrap())
     );
     assert_eq!(
         iter.next(),
-        Some(Matrix::<&i32, 1, 3, 3>::new([&2, &5, &8]).unwrap())
+        Some(ColVector::<&i32, 3>::new([&2, &5, &8]).unwrap())
     );
 
     assert_eq!(iter.next(), None);
@@ -97,3 +97,23 @@ fn iter_col() {
 
 #[test]
 fn iter_col_mut() {}
+
+#[test]
+fn non_square_rows() {
+    let mat = matrix![0, 1, 2; 3, 4, 5];
+    let rows: Vec<RowVector<i32, 3>> = mat.into_iter_row().collect();
+    assert_eq!(rows, vec![row_vector![0, 1, 2], row_vector![3, 4, 5]]);
+    assert_eq!(mat.iter_row().count(), 2);
+}
+
+#[test]
+fn non_square_cols() {
+    let mat = matrix![0, 1, 2; 3, 4, 5];
+    let cols: Vec<ColVector<i32, 2>> = mat.into_iter_col().collect();
+    assert_eq!(
+        cols,
+        vec![col_vector![0, 3], col_vector![1, 4], col_vector![2, 5]]
+    );
+    let col = mat.iter_col().nth(2).unwrap();
+    assert_eq!(*col[1], 5);
+}
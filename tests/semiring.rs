@@ -0,0 +1,103 @@
+use qmat::{
+    prelude::*,
+    semiring::{Boolean, Counting, MaxPlus, MinPlus, Semiring},
+};
+
+#[test]
+fn counting_matches_multiply() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![7, 8; 9, 10; 11, 12];
+    assert_eq!(
+        a.multiply_in::<Counting<i32>, 2, 6, 4>(&b),
+        a.multiply::<2, 6, 4>(&b)
+    );
+}
+
+#[test]
+fn min_plus_shortest_paths() {
+    let inf = u32::MAX;
+    let graph = matrix![
+        0, 4, inf, inf;
+        inf, 0, 1, inf;
+        2, inf, 0, 7;
+        inf, inf, inf, 0
+    ];
+    // with 4 vertices, every shortest path has at most 3 edges
+    let dist = graph.pow_in::<MinPlus<u32>>(3);
+    assert_eq!(
+        dist,
+        matrix![
+            0, 4, 5, 12;
+            3, 0, 1, 8;
+            2, 6, 0, 7;
+            inf, inf, inf, 0
+        ]
+    );
+}
+
+#[test]
+fn min_plus_floats() {
+    let inf = f64::INFINITY;
+    let graph = matrix![0.0, 1.5; inf, 0.0];
+    assert_eq!(graph.pow_in::<MinPlus<f64>>(5), graph);
+    assert_eq!(
+        Matrix::<f64, 2, 2, 4>::identity_in::<MinPlus<f64>>(),
+        matrix![0.0, inf; inf, 0.0]
+    );
+}
+
+#[test]
+fn max_plus_longest_paths() {
+    let ninf = i64::MIN;
+    // a DAG of tasks, weighted by their duration
+    let dag = matrix![
+        ninf, 3, 2, ninf;
+        ninf, ninf, ninf, 4;
+        ninf, ninf, ninf, 6;
+        ninf, ninf, ninf, ninf
+    ];
+    let two_steps = dag.pow_in::<MaxPlus<i64>>(2);
+    assert_eq!(two_steps[[0, 3]], 8);
+    assert_eq!(two_steps[[0, 1]], ninf);
+    assert_eq!(MaxPlus::<f32>::zero(), f32::NEG_INFINITY);
+}
+
+#[test]
+fn boolean_reachability() {
+    let graph = matrix![
+        false, true, false;
+        false, false, true;
+        false, false, false
+    ];
+    assert_eq!(
+        graph.pow_in::<Boolean>(2),
+        matrix![false, false, true; false, false, false; false, false, false]
+    );
+    assert_eq!(
+        graph.pow_in::<Boolean>(3),
+        matrix![false, false, false; false, false, false; false, false, false]
+    );
+    assert_eq!(graph.pow_in::<Boolean>(0), Matrix::identity_in::<Boolean>());
+}
+
+#[test]
+fn pow_in_counts_walks() {
+    let graph = matrix![0u64, 1; 1, 1];
+    // fibonacci numbers
+    assert_eq!(graph.pow_in::<Counting<u64>>(10), matrix![34, 55; 55, 89]);
+}
+
+#[test]
+fn tropical_zero_saturates() {
+    assert_eq!(MinPlus::<u8>::mul(u8::MAX, 3), u8::MAX);
+    assert_eq!(MinPlus::<u8>::mul(250, 10), u8::MAX);
+    assert_eq!(MaxPlus::<i8>::mul(i8::MIN, 5), i8::MIN);
+    assert_eq!(MaxPlus::<i8>::mul(-100, -100), i8::MIN);
+}
+
+#[test]
+#[should_panic(expected = "RES_LEN must be the number of elements in the product")]
+fn multiply_in_bad_res_len() {
+    let a = matrix![true, false; false, true];
+    let _ = a.multiply_in::<Boolean, 2, 4, 3>(&a);
+}
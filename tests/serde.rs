@@ -0,0 +1,141 @@
+use qmat::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    #[serde(with = "qmat::serde::nested")]
+    mat: Matrix<i32, 2, 3, 6>,
+    #[serde(with = "qmat::serde::nested")]
+    dmat: DMatrix<i32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flat {
+    #[serde(with = "qmat::serde::flat")]
+    mat: Matrix<i32, 2, 2, 4>,
+    #[serde(with = "qmat::serde::flat")]
+    dmat: DMatrix<i32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Structured {
+    #[serde(with = "qmat::serde::structured")]
+    mat: Matrix<i32, 1, 2, 2>,
+    #[serde(with = "qmat::serde::structured")]
+    dmat: DMatrix<i32>,
+}
+
+#[cfg(test)]
+mod nested {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let val = Nested {
+            mat: matrix![1, 2, 3; 4, 5, 6],
+            dmat: DMatrix::new(3, 1, vec![7, 8, 9]).unwrap(),
+        };
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#"{"mat":[[1,2,3],[4,5,6]],"dmat":[[7],[8],[9]]}"#);
+        assert_eq!(serde_json::from_str::<Nested>(&json).unwrap(), val);
+    }
+
+    #[test]
+    fn ragged() {
+        let json = r#"{"mat":[[1,2,3],[4,5]],"dmat":[[7]]}"#;
+        let msg = serde_json::from_str::<Nested>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("row 1 has 2 elements"), "{}", msg);
+    }
+
+    #[test]
+    fn wrong_shape() {
+        let json = r#"{"mat":[[1,2],[3,4],[5,6]],"dmat":[[7]]}"#;
+        assert!(serde_json::from_str::<Nested>(json).is_err());
+    }
+
+    #[test]
+    fn no_cols_bincode() {
+        let val = Nested {
+            mat: matrix![1, 2, 3; 4, 5, 6],
+            dmat: DMatrix::new(3, 0, vec![]).unwrap(),
+        };
+        let bytes = bincode::serialize(&val).unwrap();
+        let back = bincode::deserialize::<Nested>(&bytes).unwrap();
+        assert_eq!((back.dmat.rows(), back.dmat.cols()), (3, 0));
+        assert_eq!(back, val);
+    }
+}
+
+#[cfg(test)]
+mod flat {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let val = Flat {
+            mat: matrix![1, 2; 3, 4],
+            dmat: DMatrix::new(1, 2, vec![5, 6]).unwrap(),
+        };
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, r#"{"mat":[1,2,3,4],"dmat":[1,2,[5,6]]}"#);
+        assert_eq!(serde_json::from_str::<Flat>(&json).unwrap(), val);
+    }
+
+    #[test]
+    fn wrong_len() {
+        let json = r#"{"mat":[1,2,3],"dmat":[1,2,[5,6]]}"#;
+        assert!(serde_json::from_str::<Flat>(json).is_err());
+        let json = r#"{"mat":[1,2,3,4,5],"dmat":[1,2,[5,6]]}"#;
+        assert!(serde_json::from_str::<Flat>(json).is_err());
+        let json = r#"{"mat":[1,2,3,4],"dmat":[2,2,[5,6]]}"#;
+        assert!(serde_json::from_str::<Flat>(json).is_err());
+    }
+
+    #[test]
+    fn bincode_has_no_overhead() {
+        let val = Flat {
+            mat: matrix![1, 2; 3, 4],
+            dmat: DMatrix::new(1, 2, vec![5, 6]).unwrap(),
+        };
+        let bytes = bincode::serialize(&val).unwrap();
+        // 4 elements, then rows, cols, the length prefix and 2 elements
+        assert_eq!(bytes.len(), 4 * 4 + 3 * 8 + 2 * 4);
+        assert_eq!(bincode::deserialize::<Flat>(&bytes).unwrap(), val);
+    }
+}
+
+#[cfg(test)]
+mod structured {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let val = Structured {
+            mat: row_vector![1, 2],
+            dmat: DMatrix::new(2, 1, vec![3, 4]).unwrap(),
+        };
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(
+            json,
+            r#"{"mat":{"rows":1,"cols":2,"data":[1,2]},"dmat":{"rows":2,"cols":1,"data":[3,4]}}"#
+        );
+        assert_eq!(serde_json::from_str::<Structured>(&json).unwrap(), val);
+    }
+
+    #[test]
+    fn dmatrix_default_impl() {
+        let dmat = DMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let json = serde_json::to_string(&dmat).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0,4.0]}"#);
+        assert_eq!(serde_json::from_str::<DMatrix<f64>>(&json).unwrap(), dmat);
+
+        let bad = r#"{"rows":2,"cols":2,"data":[1.0]}"#;
+        assert!(serde_json::from_str::<DMatrix<f64>>(bad).is_err());
+
+        // rows * cols overflows to 0 on 64-bit targets, which mustn't match the empty data
+        let overflowing = r#"{"rows":4294967296,"cols":4294967296,"data":[]}"#;
+        assert!(serde_json::from_str::<DMatrix<f64>>(overflowing).is_err());
+    }
+}
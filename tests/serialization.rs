@@ -7,9 +7,72 @@ fn serialize() {
     assert_eq!(serialized, "{\"rows\":2,\"cols\":2,\"data\":[1,2,3,4]}")
 }
 
-/*#[test]
-fn deserialize() { // ! FAILS !
+#[test]
+fn deserialize() {
     let serialized = "{\"rows\":2,\"cols\":2,\"data\":[1,2,3,4]}";
     let deserialized: Matrix<i32, 2, 2, 4> = serde_json::from_str(serialized).unwrap();
-    println!("{:?}", deserialized)
-}*/
+    assert_eq!(*deserialized.as_flat_array(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn deserialize_any_field_order() {
+    let serialized = "{\"data\":[1,2,3,4,5,6],\"cols\":3,\"rows\":2}";
+    let deserialized: Matrix<i32, 2, 3, 6> = serde_json::from_str(serialized).unwrap();
+    assert_eq!(deserialized, matrix![1, 2, 3; 4, 5, 6]);
+}
+
+#[test]
+fn deserialize_seq() {
+    let serialized = "[2,1,[1.5,-2.5]]";
+    let deserialized: Matrix<f64, 2, 1, 2> = serde_json::from_str(serialized).unwrap();
+    assert_eq!(deserialized, col_vector![1.5, -2.5]);
+}
+
+#[test]
+fn deserialize_wrong_dims() {
+    let serialized = "{\"rows\":3,\"cols\":2,\"data\":[1,2,3,4,5,6]}";
+    let res: Result<Matrix<i32, 2, 3, 6>, _> = serde_json::from_str(serialized);
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("2 rows"), "{}", msg);
+
+    let serialized = "{\"rows\":2,\"cols\":2,\"data\":[1,2,3,4]}";
+    let res: Result<Matrix<i32, 2, 3, 6>, _> = serde_json::from_str(serialized);
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("3 cols"), "{}", msg);
+}
+
+#[test]
+fn deserialize_wrong_len() {
+    let serialized = "{\"rows\":2,\"cols\":2,\"data\":[1,2,3]}";
+    let res: Result<Matrix<i32, 2, 2, 4>, _> = serde_json::from_str(serialized);
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("4 elements"), "{}", msg);
+}
+
+#[test]
+fn deserialize_missing_and_duplicate_fields() {
+    let res: Result<Matrix<i32, 2, 2, 4>, _> = serde_json::from_str("{\"rows\":2,\"cols\":2}");
+    assert!(res.is_err());
+    let res: Result<Matrix<i32, 1, 1, 1>, _> =
+        serde_json::from_str("{\"rows\":1,\"rows\":1,\"cols\":1,\"data\":[1]}");
+    assert!(res.is_err());
+}
+
+#[test]
+fn json_round_trip_large() {
+    let mat = Matrix::<u16, 8, 8, 64>::new(std::array::from_fn(|i| i as u16)).unwrap();
+    let serialized = serde_json::to_string(&mat).unwrap();
+    let deserialized: Matrix<u16, 8, 8, 64> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(mat, deserialized);
+}
+
+#[test]
+fn bincode_round_trip() {
+    let mat = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let bytes = bincode::serialize(&mat).unwrap();
+    let deserialized: Matrix<f64, 2, 3, 6> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(mat, deserialized);
+
+    let res: Result<Matrix<f64, 3, 2, 6>, _> = bincode::deserialize(&bytes);
+    assert!(res.is_err());
+}
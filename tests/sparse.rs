@@ -0,0 +1,31 @@
+use qmat::{prelude::*, sparse::CooMatrix};
+
+#[test]
+fn to_dense_sums_duplicates() {
+    let mut sparse = CooMatrix::new(2, 3);
+    sparse.push(Position(0, 2), 1.5);
+    sparse.push(Position(1, 0), 2.0);
+    sparse.push(Position(0, 2), 0.5);
+    assert_eq!(sparse.nnz(), 3);
+    assert_eq!(
+        sparse.to_dense(),
+        DMatrix::from_rows(vec![vec![0.0, 0.0, 2.0], vec![2.0, 0.0, 0.0]]).unwrap()
+    );
+}
+
+#[test]
+fn from_dense_skips_zeros() {
+    let dense: DMatrix<i32> = matrix![0, 3; -1, 0].into();
+    let sparse = CooMatrix::from_dense(&dense);
+    assert_eq!(
+        sparse.entries(),
+        &[(Position(0, 1), 3), (Position(1, 0), -1)]
+    );
+    assert_eq!(sparse.to_dense(), dense);
+}
+
+#[test]
+#[should_panic]
+fn push_out_of_bounds() {
+    CooMatrix::new(2, 2).push(Position(2, 0), 1);
+}
@@ -0,0 +1,69 @@
+use qmat::{prelude::*, summation::Summation};
+
+const ALL: [Summation; 4] = [
+    Summation::Naive,
+    Summation::Pairwise,
+    Summation::KahanBabuska,
+    Summation::Compensated,
+];
+
+#[test]
+fn agrees_on_exact_sums() {
+    let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let b = matrix![7.0, 8.0; 9.0, 10.0; 11.0, 12.0];
+    let expected = a.multiply::<2, 6, 4>(&b);
+    for summation in ALL {
+        assert_eq!(a.multiply_with::<2, 6, 4>(&b, summation), expected);
+        assert_eq!(a.sum_with(summation), 21.0);
+        assert_eq!(
+            col_vector![3.0, 4.0].dot_with(&col_vector![3.0, 4.0], summation),
+            25.0
+        );
+        assert_eq!(col_vector![3.0f32, 4.0].norm_with(summation), 5.0);
+    }
+    assert_eq!(Summation::default(), Summation::Naive);
+}
+
+#[test]
+fn pairwise_reduces_error() {
+    let mat = Matrix::<f64, 32, 32, 1024>::new([0.1; 1024]).unwrap();
+    // 1024 is a power of two, so this is exact
+    let reference = 0.1 * 1024.0;
+    let naive = (mat.sum_with(Summation::Naive) - reference).abs();
+    let pairwise = (mat.sum_with(Summation::Pairwise) - reference).abs();
+    assert!(naive > 0.0);
+    assert!(pairwise < naive);
+    assert_eq!(mat.sum_with(Summation::KahanBabuska), reference);
+}
+
+#[test]
+fn kahan_babuska_recovers_cancelled_terms() {
+    let a = col_vector![1e16, 1.0, -1e16, 1.0];
+    let ones = col_vector![1.0, 1.0, 1.0, 1.0];
+    assert_eq!(a.dot(&ones), 1.0);
+    assert_eq!(a.dot_with(&ones, Summation::KahanBabuska), 2.0);
+    assert_eq!(a.dot_with(&ones, Summation::Compensated), 2.0);
+    assert_eq!(a.sum_with(Summation::KahanBabuska), 2.0);
+}
+
+#[test]
+fn compensated_recovers_product_errors() {
+    // x * x = 1 + 2^-29 + 2^-60, whose last term is rounded off
+    let x = 1.0 + 2f64.powi(-30);
+    let a = col_vector![x, -(1.0 + 2f64.powi(-29))];
+    let b = col_vector![x, 1.0];
+    assert_eq!(a.dot_with(&b, Summation::Naive), 0.0);
+    assert_eq!(a.dot_with(&b, Summation::KahanBabuska), 0.0);
+    assert_eq!(a.dot_with(&b, Summation::Compensated), 2f64.powi(-60));
+
+    let mat = matrix![x, -(1.0 + 2f64.powi(-29)); 1.0, 1.0];
+    let res = mat.multiply_with::<1, 2, 2>(&b, Summation::Compensated);
+    assert_eq!(res, col_vector![2f64.powi(-60), x + 1.0]);
+}
+
+#[test]
+#[should_panic(expected = "RES_LEN must be the number of elements in the product")]
+fn multiply_with_bad_res_len() {
+    let a = matrix![1.0, 2.0; 3.0, 4.0];
+    let _ = a.multiply_with::<2, 4, 3>(&a, Summation::Pairwise);
+}
@@ -0,0 +1,124 @@
+use qmat::{
+    errors::{ParseMatrixError, ParseMatrixErrorKind},
+    prelude::*,
+};
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn aligned_columns() {
+        let mat = matrix![1, 200, 3; -40, 5, 6];
+        assert_eq!(format!("{}", mat), "[  1 200 3]\n[-40   5 6]");
+    }
+
+    #[test]
+    fn precision() {
+        let mat = row_vector![0.5, 1.0 / 3.0];
+        assert_eq!(format!("{:.3}", mat), "[0.500 0.333]");
+    }
+
+    #[test]
+    fn width_and_alignment() {
+        let mat = matrix![1, 22; 333, 4];
+        assert_eq!(format!("{:4}", mat), "[   1   22]\n[ 333    4]");
+        assert_eq!(format!("{:<3}", mat), "[1   22 ]\n[333 4  ]");
+        assert_eq!(format!("{:*^5}", mat), "[**1** *22**]\n[*333* **4**]");
+    }
+
+    #[test]
+    fn dmatrix() {
+        let mat = DMatrix::new(2, 1, vec![1.5, -2.25]).unwrap();
+        assert_eq!(format!("{:.1}", mat), "[ 1.5]\n[-2.2]");
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mat = matrix![1.25, -200.0, 3.0; -40.5, 5.0, 6.125];
+        let parsed: Matrix<f64, 2, 3, 6> = mat.to_string().parse().unwrap();
+        assert_eq!(parsed, mat);
+    }
+
+    #[test]
+    fn matlab_style() {
+        let mat: Matrix<i32, 2, 3, 6> = "[1 2 3; 4 5 6]".parse().unwrap();
+        assert_eq!(mat, matrix![1, 2, 3; 4, 5, 6]);
+        let mat: Matrix<i32, 2, 2, 4> = "[1, 2; 3, 4;]".parse().unwrap();
+        assert_eq!(mat, matrix![1, 2; 3, 4]);
+    }
+
+    #[test]
+    fn plain_and_nested() {
+        let mat: Matrix<i32, 2, 2, 4> = "1 2\n\n3 4\n".parse().unwrap();
+        assert_eq!(mat, matrix![1, 2; 3, 4]);
+        let mat: Matrix<i32, 2, 2, 4> = "[[1, 2], [3, 4]]".parse().unwrap();
+        assert_eq!(mat, matrix![1, 2; 3, 4]);
+    }
+
+    #[test]
+    fn invalid_element() {
+        let err = "[1 2]\n[3 four]"
+            .parse::<Matrix<i32, 2, 2, 4>>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseMatrixError {
+                line: 2,
+                col: 4,
+                kind: ParseMatrixErrorKind::InvalidElement("four".to_string()),
+            }
+        );
+        assert_eq!(err.to_string(), "line 2, column 4: invalid element `four`");
+    }
+
+    #[test]
+    fn ragged_row() {
+        let err = "1 2\n 3 4 5".parse::<DMatrix<i32>>().unwrap_err();
+        assert_eq!((err.line, err.col), (2, 2));
+        assert_eq!(
+            err.kind,
+            ParseMatrixErrorKind::RaggedRow {
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let err = "[1 2; 3 4; 5 6]"
+            .parse::<Matrix<i32, 2, 2, 4>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.col), (1, 12));
+        assert_eq!(
+            err.kind,
+            ParseMatrixErrorKind::DimensionMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn unbalanced_brackets() {
+        let err = "[1 2]]".parse::<DMatrix<i32>>().unwrap_err();
+        assert_eq!((err.line, err.col), (1, 6));
+        let err = "[1 2\n [3 4]".parse::<DMatrix<i32>>().unwrap_err();
+        assert_eq!(err.kind, ParseMatrixErrorKind::UnbalancedBracket);
+        assert_eq!((err.line, err.col), (1, 1));
+    }
+
+    #[test]
+    fn dmatrix() {
+        let mat: DMatrix<u8> = "[1 2 3]\n[4 5 6]".parse().unwrap();
+        assert_eq!(mat.rows(), 2);
+        assert_eq!(mat.cols(), 3);
+        assert_eq!(mat.to_string(), "[1 2 3]\n[4 5 6]");
+    }
+}
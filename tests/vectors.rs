@@ -0,0 +1,99 @@
+use qmat::prelude::*;
+
+fn round(x: f64) -> f64 {
+    (x * 1000.0).round() / 1000.0
+}
+
+#[test]
+fn norm() {
+    let vec = col_vector![1.0, 2.0, 2.0];
+    assert_eq!(vec.norm_squared(), 9.0);
+    assert_eq!(vec.norm(), 3.0);
+}
+
+#[test]
+fn normalize() {
+    let vec = col_vector![0.0, -5.0, 0.0];
+    assert_eq!(vec.normalize(), col_vector![0.0, -1.0, 0.0]);
+    assert_eq!(vec.try_normalize(), Some(col_vector![0.0, -1.0, 0.0]));
+    assert!(col_vector![0.0f32, 0.0, 0.0].try_normalize().is_none());
+}
+
+#[test]
+fn cross() {
+    let a = col_vector![2, 3, 4];
+    let b = col_vector![5, 6, 7];
+    let c = a.cross(&b);
+    assert_eq!(c, col_vector![-3, 6, -3]);
+    assert_eq!(c.dot(&a), 0);
+    assert_eq!(c.dot(&b), 0);
+    assert_eq!(b.cross(&a), col_vector![3, -6, 3]);
+}
+
+#[test]
+fn angle_between() {
+    let a = col_vector![1.0, 0.0, 0.0];
+    let b = col_vector![1.0, 1.0, 0.0];
+    assert_eq!(
+        round(a.angle_between(&b)),
+        round(std::f64::consts::FRAC_PI_4)
+    );
+    assert_eq!(a.angle_between(&a), 0.0);
+    assert_eq!(
+        round(a.angle_between(&a.mul_scalar(-1.0))),
+        round(std::f64::consts::PI)
+    );
+}
+
+#[test]
+fn angle_between_zero_vector() {
+    let zero = col_vector![0.0f64, 0.0, 0.0];
+    let a = col_vector![1.0, 2.0, 3.0];
+    assert!(a.angle_between(&zero).is_nan());
+    assert!(zero.angle_between(&a).is_nan());
+    assert!(zero.angle_between(&zero).is_nan());
+}
+
+#[test]
+fn project_and_reject() {
+    let vec = col_vector![1.0, 2.0, 3.0];
+    let axis = col_vector![0.0, 0.0, 2.0];
+    let projected = vec.project_onto(&axis);
+    let rejected = vec.reject_from(&axis);
+    assert_eq!(projected, col_vector![0.0, 0.0, 3.0]);
+    assert_eq!(rejected, col_vector![1.0, 2.0, 0.0]);
+    assert_eq!(projected + rejected, vec);
+}
+
+#[test]
+fn project_onto_zero_vector() {
+    let vec = col_vector![1.0, 2.0, 3.0];
+    let zero = col_vector![0.0f64, 0.0, 0.0];
+    assert!(vec.project_onto(&zero).iter().all(|x| x.is_nan()));
+    assert!(vec.reject_from(&zero).iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn outer() {
+    let a = col_vector![1, 2, 3];
+    let b = col_vector![4, 5];
+    let outer: Matrix<i32, 3, 2, 6> = a.outer(&b);
+    assert_eq!(*outer.as_flat_array(), [4, 5, 8, 10, 12, 15]);
+}
+
+#[test]
+#[should_panic]
+fn outer_wrong_len() {
+    let a = col_vector![1, 2, 3];
+    let b = col_vector![4, 5];
+    let _: Matrix<i32, 3, 2, 7> = a.outer(&b);
+}
+
+#[test]
+fn lerp() {
+    let a = col_vector![0.0, 0.0, 0.0];
+    let b = col_vector![2.0, -4.0, 8.0];
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), col_vector![1.0, -2.0, 4.0]);
+}
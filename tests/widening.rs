@@ -0,0 +1,47 @@
+use qmat::prelude::*;
+
+#[test]
+fn widening_multiply_u8() {
+    let a = matrix![255u8, 255; 255, 255];
+    let res = a.widening_multiply::<u32, 2, 4, 4>(&a);
+    assert_eq!(res, matrix![130_050, 130_050; 130_050, 130_050]);
+}
+
+#[test]
+fn widening_multiply_matches_multiply() {
+    let a = matrix![1i8, -2, 3; 4, 5, -6];
+    let b = matrix![7i8, 8; 9, -10; 11, 12];
+    let narrow = a.multiply::<2, 6, 4>(&b);
+    let wide = a.widening_multiply::<i32, 2, 6, 4>(&b);
+    assert_eq!(
+        wide,
+        Matrix::new(narrow.into_flat_array().map(i32::from)).unwrap()
+    );
+}
+
+#[test]
+fn widening_multiply_to_vector() {
+    let a = matrix![-128i8, -128; 127, 127];
+    let v = col_vector![-128i8, -128];
+    let res: ColVector<i32, 2> = a.widening_multiply::<i32, 1, 2, 2>(&v);
+    assert_eq!(res, col_vector![32_768, -32_512]);
+}
+
+#[test]
+fn widening_dot() {
+    let a = col_vector![200u8, 200, 200];
+    assert_eq!(a.widening_dot::<u32>(&a), 120_000);
+    assert_eq!(a.widening_dot::<u16>(&col_vector![1, 2, 3]), 1200);
+
+    let b = col_vector![3e20f32, 4e20];
+    assert!(b.dot(&b).is_infinite());
+    let wide = b.widening_dot::<f64>(&b);
+    assert!((wide.sqrt() / 5e20 - 1.0).abs() < 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "RES_LEN must be the number of elements in the product")]
+fn widening_multiply_bad_res_len() {
+    let a = matrix![1u8, 2; 3, 4];
+    let _ = a.widening_multiply::<u32, 2, 4, 5>(&a);
+}